@@ -5,10 +5,17 @@ use bevy_time::prelude::*;
 #[derive(Resource)]
 pub struct BabySpawnerConfig {
     pub per_sec: f64,
+    /// Only people aged within `[min_parent_age, max_parent_age)` years are picked as parents.
+    pub min_parent_age: f32,
+    pub max_parent_age: f32,
 }
 
 impl Default for BabySpawnerConfig {
     fn default() -> Self {
-        Self { per_sec: 0.0 }
+        Self {
+            per_sec: 0.0,
+            min_parent_age: 18.0,
+            max_parent_age: 45.0,
+        }
     }
 }
@@ -5,4 +5,6 @@ use bevy_time::prelude::*;
 #[derive(Event, Debug, Clone, Copy)]
 pub struct BabyBorn {
     pub entity: Entity,
+    /// `None` when no eligible pair of parents was alive at birth.
+    pub parents: Option<(Entity, Entity)>,
 }
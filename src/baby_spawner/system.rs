@@ -3,10 +3,11 @@ use bevy_ecs::prelude::*;
 use bevy_time::{Time, Real};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
+use rand::seq::index;
 use rand_distr::{Distribution, Poisson};
 
 use crate::baby_spawner::{config::BabySpawnerConfig, events::BabyBorn};
-use crate::person::Person;
+use crate::person::{Parents, Person};
 
 #[derive(Resource)]
 pub struct GameRNG(pub StdRng);
@@ -22,6 +23,7 @@ pub fn spawn_babies(
     time: Res<Time<Real>>,
     cfg: Res<BabySpawnerConfig>,
     mut rng: ResMut<GameRNG>,
+    people: Query<(Entity, &Person)>,
     mut writer: EventWriter<BabyBorn>,
 ) {
     let dt = time.delta_secs_f64();
@@ -31,8 +33,29 @@ pub fn spawn_babies(
     }
 
     let n = Poisson::new(lambda).unwrap().sample(&mut rng.0) as usize;
+    if n == 0 {
+        return;
+    }
+
+    let mut adults: Vec<Entity> = people
+        .iter()
+        .filter(|(_, p)| p.age >= cfg.min_parent_age && p.age < cfg.max_parent_age)
+        .map(|(e, _)| e)
+        .collect();
+    adults.sort(); // query order must not leak into the draw
+
     for _ in 0..n {
-        let entity = commands.spawn(Person::new()).id();
-        writer.write(BabyBorn { entity });
+        let parents = if adults.len() >= 2 {
+            let pick = index::sample(&mut rng.0, adults.len(), 2);
+            Some((adults[pick.index(0)], adults[pick.index(1)]))
+        } else {
+            None
+        };
+        let mut baby = commands.spawn(Person::new());
+        if let Some((a, b)) = parents {
+            baby.insert(Parents(a, b));
+        }
+        let entity = baby.id();
+        writer.write(BabyBorn { entity, parents });
     }
 }
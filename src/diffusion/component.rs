@@ -0,0 +1,24 @@
+use bevy_ecs::prelude::*;
+
+/// Has taken up the idea (IC and LT models).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Adopted {
+    /// `Time<Real>` seconds at adoption.
+    pub at: f64,
+    /// Independent cascade: the one chance to pass it on has been used.
+    pub spent: bool,
+}
+
+impl Adopted {
+    pub fn new(at: f64) -> Self {
+        Self { at, spent: false }
+    }
+}
+
+/// Linear threshold: share of weighted incoming influence needed to adopt.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Threshold(pub f64);
+
+/// DeGroot: a position in `[0, 1]`, averaged with neighbours each round.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Opinion(pub f64);
@@ -0,0 +1,39 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Clone, Debug)]
+pub enum DiffusionModel {
+    /// Each new adopter gets one chance to convert each neighbour, with
+    /// probability `p` times edge weight and personality modulation.
+    IndependentCascade { p: f64 },
+    /// Adopt once adopting neighbours carry at least a personal threshold
+    /// (uniform in `[0, 1]`) of one's total incoming edge weight.
+    LinearThreshold,
+    /// Opinions move towards the weighted mean of neighbours' opinions,
+    /// keeping `self_weight` of their own.
+    DeGroot { self_weight: f64 },
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct DiffusionConfig {
+    pub model: DiffusionModel,
+    /// Real seconds between diffusion rounds.
+    pub interval_secs: f64,
+    /// Random adopters picked once the network exists (IC/LT).
+    pub initial_adopters: usize,
+    /// How much a sender's extraversion above 0.5 boosts what they pass on.
+    pub extraversion_gain: f64,
+    /// How much a receiver's openness above 0.5 boosts what they take in.
+    pub openness_gain: f64,
+}
+
+impl Default for DiffusionConfig {
+    fn default() -> Self {
+        Self {
+            model: DiffusionModel::IndependentCascade { p: 0.1 },
+            interval_secs: 1.0,
+            initial_adopters: 5,
+            extraversion_gain: 1.0,
+            openness_gain: 1.0,
+        }
+    }
+}
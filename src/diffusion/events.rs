@@ -0,0 +1,12 @@
+use bevy_ecs::prelude::*;
+
+/// Summary after each diffusion round.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DiffusionStep {
+    /// Adopters among the network's nodes.
+    pub adopted: usize,
+    /// Nodes in the network (owners of a graph).
+    pub population: usize,
+    /// Mean over nodes holding an `Opinion`, if any do.
+    pub mean_opinion: Option<f64>,
+}
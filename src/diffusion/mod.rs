@@ -0,0 +1,11 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::{Adopted, Opinion, Threshold};
+pub use config::{DiffusionConfig, DiffusionModel};
+pub use events::DiffusionStep;
+pub use plugin::DiffusionPlugin;
+pub use system::transmission;
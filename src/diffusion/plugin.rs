@@ -0,0 +1,41 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::marker::PhantomData;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::diffusion::config::DiffusionConfig;
+use crate::diffusion::events::DiffusionStep;
+use crate::diffusion::system::{assign_opinions, diffuse, seed_adopters};
+use crate::graph::EdgeWeight;
+
+/// Spreads adoption or opinions along `Graph<V>` edges, weighted by
+/// `EdgeWeight` and modulated by `Personality`.
+pub struct DiffusionPlugin<V> {
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V> DiffusionPlugin<V> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<V> Default for DiffusionPlugin<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: EdgeWeight + Send + Sync + 'static> Plugin for DiffusionPlugin<V> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiffusionConfig>()
+            .init_resource::<GameRNG>()
+            .add_event::<DiffusionStep>()
+            .add_systems(
+                Update,
+                (seed_adopters::<V>, assign_opinions::<V>, diffuse::<V>).chain(),
+            );
+    }
+}
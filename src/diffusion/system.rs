@@ -0,0 +1,231 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::Rng;
+use rand::seq::index;
+use std::collections::{HashMap, HashSet};
+
+use crate::baby_spawner::system::GameRNG;
+use crate::diffusion::component::{Adopted, Opinion, Threshold};
+use crate::diffusion::config::{DiffusionConfig, DiffusionModel};
+use crate::diffusion::events::DiffusionStep;
+use crate::graph::{EdgeWeight, Graph};
+use crate::personality::Personality;
+
+// Extraverts pass things on more readily.
+fn sender_gain(cfg: &DiffusionConfig, p: Option<&Personality>) -> f64 {
+    (1.0 + cfg.extraversion_gain * (p.map_or(0.5, |p| p.extraversion) - 0.5)).max(0.0)
+}
+
+// Open people take things in more readily.
+fn receiver_gain(cfg: &DiffusionConfig, p: Option<&Personality>) -> f64 {
+    (1.0 + cfg.openness_gain * (p.map_or(0.5, |p| p.openness) - 0.5)).max(0.0)
+}
+
+/// Multiplier on transmission from `sender` to `receiver`; 1 when both are
+/// average (0.5) or have no `Personality`.
+pub fn transmission(
+    cfg: &DiffusionConfig,
+    sender: Option<&Personality>,
+    receiver: Option<&Personality>,
+) -> f64 {
+    sender_gain(cfg, sender) * receiver_gain(cfg, receiver)
+}
+
+// Edges in entity order, so results do not depend on HashMap iteration.
+fn sorted_edges<V: EdgeWeight>(g: &Graph<V>) -> Vec<(Entity, f64)> {
+    let mut edges: Vec<(Entity, f64)> = g.iter().map(|(to, v)| (to, v.weight().max(0.0))).collect();
+    edges.sort_by_key(|&(to, _)| to);
+    edges
+}
+
+/// Pick `initial_adopters` random nodes once the network exists, unless
+/// adopters were placed by hand.
+pub fn seed_adopters<V: Send + Sync + 'static>(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    cfg: Res<DiffusionConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut done: Local<bool>,
+    graphs: Query<Entity, With<Graph<V>>>,
+    adopted: Query<(), With<Adopted>>,
+) {
+    if *done || matches!(cfg.model, DiffusionModel::DeGroot { .. }) {
+        return;
+    }
+    let mut nodes: Vec<Entity> = graphs.iter().collect();
+    if nodes.is_empty() {
+        return;
+    }
+    *done = true;
+    if !adopted.is_empty() {
+        return;
+    }
+    nodes.sort();
+    let now = time.elapsed_secs_f64();
+    let k = cfg.initial_adopters.min(nodes.len());
+    for i in index::sample(&mut rng.0, nodes.len(), k) {
+        commands.entity(nodes[i]).insert(Adopted::new(now));
+    }
+}
+
+/// Under DeGroot, nodes without an `Opinion` start from a uniform one.
+pub fn assign_opinions<V: Send + Sync + 'static>(
+    mut commands: Commands,
+    cfg: Res<DiffusionConfig>,
+    mut rng: ResMut<GameRNG>,
+    graphs: Query<Entity, (With<Graph<V>>, Without<Opinion>)>,
+) {
+    if !matches!(cfg.model, DiffusionModel::DeGroot { .. }) {
+        return;
+    }
+    let mut nodes: Vec<Entity> = graphs.iter().collect();
+    nodes.sort();
+    for e in nodes {
+        commands.entity(e).insert(Opinion(rng.0.random()));
+    }
+}
+
+/// One round of the configured model every `interval_secs`. Updates are
+/// synchronous: everyone reacts to the state at the start of the round.
+#[allow(clippy::too_many_arguments)]
+pub fn diffuse<V: EdgeWeight + Send + Sync + 'static>(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    cfg: Res<DiffusionConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut last: Local<Option<f64>>,
+    graphs: Query<(Entity, &Graph<V>)>,
+    traits: Query<&Personality>,
+    mut adopted: Query<&mut Adopted>,
+    thresholds: Query<&Threshold>,
+    mut opinions: Query<&mut Opinion>,
+    mut steps: EventWriter<DiffusionStep>,
+) {
+    let now = time.elapsed_secs_f64();
+    if last.is_some_and(|t| now - t < cfg.interval_secs) {
+        return;
+    }
+    *last = Some(now);
+
+    let mut nodes: Vec<(Entity, &Graph<V>)> = graphs.iter().collect();
+    if nodes.is_empty() {
+        return;
+    }
+    nodes.sort_by_key(|&(e, _)| e);
+    let gain =
+        |from: Entity, to: Entity| transmission(&cfg, traits.get(from).ok(), traits.get(to).ok());
+
+    let mut converts: Vec<Entity> = Vec::new();
+    match cfg.model {
+        DiffusionModel::IndependentCascade { p } => {
+            let mut taken: HashSet<Entity> = HashSet::new();
+            for &(from, g) in &nodes {
+                {
+                    let Ok(mut a) = adopted.get_mut(from) else {
+                        continue;
+                    };
+                    if a.spent {
+                        continue;
+                    }
+                    a.spent = true;
+                }
+                for (to, w) in sorted_edges(g) {
+                    if adopted.contains(to) || !graphs.contains(to) || taken.contains(&to) {
+                        continue;
+                    }
+                    if rng.0.random_bool((p * w * gain(from, to)).clamp(0.0, 1.0)) {
+                        taken.insert(to);
+                        converts.push(to);
+                    }
+                }
+            }
+            for &e in &converts {
+                commands.entity(e).insert(Adopted::new(now));
+            }
+        }
+        DiffusionModel::LinearThreshold => {
+            let mut incoming: HashMap<Entity, Vec<(Entity, f64)>> = HashMap::new();
+            for &(from, g) in &nodes {
+                for (to, w) in sorted_edges(g) {
+                    incoming.entry(to).or_default().push((from, w));
+                }
+            }
+            for &(e, _) in &nodes {
+                if adopted.contains(e) {
+                    continue;
+                }
+                let Some(sources) = incoming.get(&e) else {
+                    continue;
+                };
+                let total: f64 = sources.iter().map(|&(_, w)| w).sum();
+                if total <= 0.0 {
+                    continue;
+                }
+                let pressure = sources
+                    .iter()
+                    .filter(|&&(from, _)| adopted.contains(from))
+                    .map(|&(from, w)| w * gain(from, e))
+                    .sum::<f64>()
+                    / total;
+                let theta = match thresholds.get(e) {
+                    Ok(t) => t.0,
+                    Err(_) => {
+                        let t = rng.0.random::<f64>();
+                        commands.entity(e).insert(Threshold(t));
+                        t
+                    }
+                };
+                if pressure > 0.0 && pressure >= theta {
+                    converts.push(e);
+                }
+            }
+            for &e in &converts {
+                commands.entity(e).insert(Adopted {
+                    at: now,
+                    spent: true,
+                });
+            }
+        }
+        DiffusionModel::DeGroot { self_weight } => {
+            let current: HashMap<Entity, f64> = nodes
+                .iter()
+                .filter_map(|&(e, _)| opinions.get(e).ok().map(|o| (e, o.0)))
+                .collect();
+            let mut next: Vec<(Entity, f64)> = Vec::new();
+            for &(e, g) in &nodes {
+                let Some(&mine) = current.get(&e) else {
+                    continue;
+                };
+                let (mut weight, mut sum) = (0.0, 0.0);
+                for (other, w) in sorted_edges(g) {
+                    if let Some(&o) = current.get(&other) {
+                        let w = w * sender_gain(&cfg, traits.get(other).ok());
+                        weight += w;
+                        sum += w * o;
+                    }
+                }
+                if weight <= 0.0 {
+                    continue;
+                }
+                let s =
+                    ((1.0 - self_weight) * receiver_gain(&cfg, traits.get(e).ok())).clamp(0.0, 1.0);
+                next.push((e, (1.0 - s) * mine + s * sum / weight));
+            }
+            for (e, v) in next {
+                if let Ok(mut o) = opinions.get_mut(e) {
+                    o.0 = v;
+                }
+            }
+        }
+    }
+
+    let held: Vec<f64> = nodes
+        .iter()
+        .filter_map(|&(e, _)| opinions.get(e).ok().map(|o| o.0))
+        .collect();
+    steps.write(DiffusionStep {
+        adopted: nodes.iter().filter(|&&(e, _)| adopted.contains(e)).count() + converts.len(),
+        population: nodes.len(),
+        mean_opinion: (!held.is_empty()).then(|| held.iter().sum::<f64>() / held.len() as f64),
+    });
+}
@@ -0,0 +1,29 @@
+use bevy_ecs::prelude::*;
+
+/// Money held by a person or a job.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Account {
+    pub balance: f64,
+}
+
+impl Account {
+    pub fn new(balance: f64) -> Self {
+        Self { balance }
+    }
+
+    pub fn deposit(&mut self, amount: f64) {
+        self.balance += amount.max(0.0);
+    }
+
+    /// Take up to `amount`, never going below zero. Returns what was taken.
+    pub fn withdraw(&mut self, amount: f64) -> f64 {
+        let taken = amount.clamp(0.0, self.balance.max(0.0));
+        self.balance -= taken;
+        taken
+    }
+}
+
+/// Wages for this job are paid from `0`'s `Account` rather than its own,
+/// e.g. a firm paying for the jobs it runs.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Payer(pub Entity);
@@ -0,0 +1,21 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Resource, Clone, Debug)]
+pub struct PayrollConfig {
+    /// Simulated days between paydays.
+    pub period_days: f64,
+    /// Balance a job's `Account` opens with.
+    pub initial_job_funds: f64,
+    /// Balance a person's `Account` opens with.
+    pub initial_person_funds: f64,
+}
+
+impl Default for PayrollConfig {
+    fn default() -> Self {
+        Self {
+            period_days: 30.0,
+            initial_job_funds: 1_000_000.0,
+            initial_person_funds: 0.0,
+        }
+    }
+}
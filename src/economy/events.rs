@@ -0,0 +1,23 @@
+use bevy_ecs::prelude::*;
+
+/// One payroll run over every job.
+#[derive(Event, Debug, Clone)]
+pub struct Payday {
+    /// Length of the pay period in simulated years.
+    pub period_years: f64,
+    /// (person, amount received) for every member of a paid role.
+    pub payments: Vec<(Entity, f64)>,
+    /// Wages owed but not paid because a job ran out of money.
+    pub shortfall: f64,
+    /// (account holder, amount paid out) for every job or payer that paid.
+    pub spent: Vec<(Entity, f64)>,
+    /// (account holder, wages owed but not paid) for every job or payer
+    /// that came up short.
+    pub unpaid: Vec<(Entity, f64)>,
+}
+
+impl Payday {
+    pub fn total(&self) -> f64 {
+        self.payments.iter().map(|&(_, a)| a).sum()
+    }
+}
@@ -0,0 +1,10 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::{Account, Payer};
+pub use config::PayrollConfig;
+pub use events::Payday;
+pub use plugin::PayrollPlugin;
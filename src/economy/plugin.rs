@@ -0,0 +1,20 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::economy::config::PayrollConfig;
+use crate::economy::events::Payday;
+use crate::economy::system::{open_accounts, run_payroll};
+use crate::person::AgingConfig;
+
+/// Bank accounts for people and jobs, and a periodic payroll paying role
+/// wages from the job's account to its members.
+pub struct PayrollPlugin;
+
+impl Plugin for PayrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PayrollConfig>()
+            .init_resource::<AgingConfig>()
+            .add_event::<Payday>()
+            .add_systems(Update, (open_accounts, run_payroll).chain());
+    }
+}
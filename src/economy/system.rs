@@ -0,0 +1,100 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use crate::economy::component::{Account, Payer};
+use crate::economy::config::PayrollConfig;
+use crate::economy::events::Payday;
+use crate::jobs::Job;
+use crate::person::{AgingConfig, Person};
+
+const DAYS_PER_YEAR: f64 = 365.0;
+
+/// Give people and jobs without an `Account` one with the configured
+/// balance. Jobs with a `Payer` use theirs instead.
+#[allow(clippy::type_complexity)]
+pub fn open_accounts(
+    mut commands: Commands,
+    cfg: Res<PayrollConfig>,
+    people: Query<Entity, (With<Person>, Without<Account>)>,
+    jobs: Query<Entity, (With<Job>, Without<Account>, Without<Payer>)>,
+) {
+    for e in people.iter() {
+        commands
+            .entity(e)
+            .insert(Account::new(cfg.initial_person_funds));
+    }
+    for e in jobs.iter() {
+        commands
+            .entity(e)
+            .insert(Account::new(cfg.initial_job_funds));
+    }
+}
+
+/// Every `period_days`, each job pays its members `wage` prorated to the
+/// period out of its own `Account`, or its `Payer`'s. A job that cannot
+/// cover the whole payroll pays everyone the same fraction of what they
+/// are owed.
+pub fn run_payroll(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<PayrollConfig>,
+    mut days: Local<f64>,
+    mut jobs: Query<(Entity, &Job, Option<&mut Account>, Option<&Payer>)>,
+    mut wallets: Query<&mut Account, Without<Job>>,
+    mut paydays: EventWriter<Payday>,
+) {
+    *days += time.delta_secs_f64() * aging.years_per_sec * DAYS_PER_YEAR;
+    if cfg.period_days <= 0.0 || *days < cfg.period_days {
+        return;
+    }
+    *days -= cfg.period_days;
+    let period_years = cfg.period_days / DAYS_PER_YEAR;
+
+    let mut order: Vec<Entity> = jobs.iter().map(|(e, ..)| e).collect();
+    order.sort();
+    let mut payday = Payday {
+        period_years,
+        payments: Vec::new(),
+        shortfall: 0.0,
+        spent: Vec::new(),
+        unpaid: Vec::new(),
+    };
+    for e in order {
+        let Ok((_, job, own, payer)) = jobs.get_mut(e) else {
+            continue;
+        };
+        let owed: Vec<(Entity, f64)> = job
+            .roles
+            .iter()
+            .filter(|(spec, _)| spec.wage > 0.0)
+            .flat_map(|(spec, members)| {
+                members
+                    .iter()
+                    .filter(|&&m| wallets.contains(m))
+                    .map(move |&m| (m, spec.wage as f64 * period_years))
+            })
+            .collect();
+        let total: f64 = owed.iter().map(|&(_, a)| a).sum();
+        if total <= 0.0 {
+            continue;
+        }
+        let (holder, paid) = match (payer, own) {
+            (Some(&Payer(p)), _) => (p, wallets.get_mut(p).map_or(0.0, |mut a| a.withdraw(total))),
+            (None, Some(mut funds)) => (e, funds.withdraw(total)),
+            (None, None) => (e, 0.0),
+        };
+        payday.spent.push((holder, paid));
+        let share = paid / total;
+        payday.shortfall += total - paid;
+        if paid < total {
+            payday.unpaid.push((holder, total - paid));
+        }
+        for (m, amount) in owed {
+            if let Ok(mut wallet) = wallets.get_mut(m) {
+                wallet.deposit(amount * share);
+                payday.payments.push((m, amount * share));
+            }
+        }
+    }
+    paydays.write(payday);
+}
@@ -0,0 +1,14 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Resource, Clone, Debug)]
+pub struct EducationConfig {
+    /// Students one attending member of the supervising role can teach at
+    /// the full schooling rate; larger classes learn proportionally slower.
+    pub class_size: f32,
+}
+
+impl Default for EducationConfig {
+    fn default() -> Self {
+        Self { class_size: 25.0 }
+    }
+}
@@ -0,0 +1,10 @@
+use bevy_ecs::prelude::*;
+
+/// `person` outgrew a student role of `job` with `years` of `Education`.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct Graduated {
+    pub person: Entity,
+    pub job: Entity,
+    pub role_index: usize,
+    pub years: f32,
+}
@@ -0,0 +1,9 @@
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use config::EducationConfig;
+pub use events::Graduated;
+pub use plugin::EducationPlugin;
+pub use system::{attend_school, graduate, teaching_ratio};
@@ -0,0 +1,27 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::education::config::EducationConfig;
+use crate::education::events::Graduated;
+use crate::education::system::{attend_school, graduate};
+use crate::gregslist::component::VacancyDirty;
+use crate::separation::Separated;
+use crate::person::AgingConfig;
+use crate::personality::LifeEvent;
+
+/// Schooling: members of roles with a `schooling` rate gain `Education`
+/// while they attend, and leave the role as graduates once its constraints
+/// no longer admit them, e.g. on aging out of a student role.
+pub struct EducationPlugin;
+
+impl Plugin for EducationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EducationConfig>()
+            .init_resource::<AgingConfig>()
+            .add_event::<Graduated>()
+            .add_event::<VacancyDirty>()
+            .add_event::<Separated>()
+            .add_event::<LifeEvent>()
+            .add_systems(Update, (attend_school, graduate).chain());
+    }
+}
@@ -0,0 +1,118 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use crate::education::config::EducationConfig;
+use crate::education::events::Graduated;
+use crate::gregslist::component::VacancyDirty;
+use crate::jobs::{Absent, Job};
+use crate::person::{AgingConfig, Education};
+use crate::personality::LifeEvent;
+use crate::separation::{separate, Separated, SeparationReason};
+
+/// Share of the schooling rate role `role_index` learns at, in `[0, 1]`:
+/// 1 without a supervising role, else limited by attending supervisors
+/// per `class_size` students.
+pub fn teaching_ratio(
+    job: &Job,
+    role_index: usize,
+    cfg: &EducationConfig,
+    absent: &Query<(), With<Absent>>,
+) -> f32 {
+    let Some((spec, students)) = job.roles.get(role_index) else {
+        return 0.0;
+    };
+    if spec.reports_to.is_none() || students.is_empty() {
+        return 1.0;
+    }
+    let teachers = job
+        .supervisors(role_index)
+        .iter()
+        .filter(|&&t| !absent.contains(t))
+        .count() as f32;
+    (teachers * cfg.class_size / students.len() as f32).min(1.0)
+}
+
+/// Attending members of student roles gain `schooling` years of
+/// `Education` per year, slowed when there are too few teachers.
+pub fn attend_school(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<EducationConfig>,
+    jobs: Query<&Job>,
+    absent: Query<(), With<Absent>>,
+    mut education: Query<&mut Education>,
+) {
+    let dt = (time.delta_secs_f64() * aging.years_per_sec) as f32;
+    if dt <= 0.0 {
+        return;
+    }
+    for job in jobs.iter() {
+        for (i, (spec, students)) in job.roles.iter().enumerate() {
+            if spec.schooling <= 0.0 {
+                continue;
+            }
+            let gain = spec.schooling * teaching_ratio(job, i, &cfg, &absent) * dt;
+            for &s in students.iter().filter(|&&s| !absent.contains(s)) {
+                match education.get_mut(s) {
+                    Ok(mut ed) => ed.years += gain,
+                    Err(_) => {
+                        commands.entity(s).insert(Education { years: gain });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Members of student roles who no longer meet the role's constraints
+/// leave it as graduates and look for work.
+pub fn graduate(
+    mut commands: Commands,
+    mut jobs: Query<(Entity, &mut Job)>,
+    people: Query<EntityRef, Without<Job>>,
+    mut graduated: EventWriter<Graduated>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut separated: EventWriter<Separated>,
+    mut life: EventWriter<LifeEvent>,
+) {
+    for (e, mut job) in jobs.iter_mut() {
+        let mut leaving: Vec<(usize, Entity, f32)> = Vec::new();
+        for (i, (spec, students)) in job.roles.iter().enumerate() {
+            if spec.schooling <= 0.0 {
+                continue;
+            }
+            for &s in students {
+                let Ok(person) = people.get(s) else {
+                    continue;
+                };
+                if spec.admits(&person).is_err() {
+                    let years = person.get::<Education>().map_or(0.0, |ed| ed.years);
+                    leaving.push((i, s, years));
+                }
+            }
+        }
+        if leaving.is_empty() {
+            continue;
+        }
+        for (i, s, years) in leaving {
+            separate(
+                &mut commands,
+                e,
+                &mut job,
+                i,
+                s,
+                SeparationReason::Graduated,
+                &mut separated,
+                &mut life,
+            );
+            graduated.write(Graduated {
+                person: s,
+                job: e,
+                role_index: i,
+                years,
+            });
+        }
+        dirty.write(VacancyDirty { job: e });
+    }
+}
@@ -0,0 +1,12 @@
+use bevy_ecs::prelude::*;
+
+/// People waiting for a seat in a compulsory role of this institution, the
+/// nearest one that admits them, as `(role_index, person)` in arrival order.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct Waitlist(pub Vec<(usize, Entity)>);
+
+impl Waitlist {
+    pub fn contains(&self, person: Entity) -> bool {
+        self.0.iter().any(|&(_, p)| p == person)
+    }
+}
@@ -0,0 +1,19 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Resource, Debug, Clone)]
+pub struct EnrolmentConfig {
+    /// Open another institution of a catalog type once this many people
+    /// wait for its compulsory seats; `None` never opens one.
+    pub open_at: Option<usize>,
+    /// Real seconds between enrolment passes.
+    pub interval_secs: f64,
+}
+
+impl Default for EnrolmentConfig {
+    fn default() -> Self {
+        Self {
+            open_at: Some(20),
+            interval_secs: 1.0,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use bevy_ecs::prelude::*;
+
+/// `person` was placed in compulsory role `role_index` of `job`.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct Enrolled {
+    pub person: Entity,
+    pub job: Entity,
+    pub role_index: usize,
+}
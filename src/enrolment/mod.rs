@@ -0,0 +1,11 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::Waitlist;
+pub use config::EnrolmentConfig;
+pub use events::Enrolled;
+pub use plugin::EnrolmentPlugin;
+pub use system::enrol_compulsory;
@@ -0,0 +1,25 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::enrolment::config::EnrolmentConfig;
+use crate::enrolment::events::Enrolled;
+use crate::enrolment::system::enrol_compulsory;
+use crate::gregslist::component::VacancyDirty;
+use crate::jobs::SpawnJob;
+use crate::person::AgingConfig;
+
+/// Compulsory roles fill themselves: everyone a compulsory role admits is
+/// enrolled at the nearest institution with room, or waitlisted there, and
+/// long waitlists open a new institution of the same catalog type.
+pub struct EnrolmentPlugin;
+
+impl Plugin for EnrolmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EnrolmentConfig>()
+            .init_resource::<AgingConfig>()
+            .add_event::<Enrolled>()
+            .add_event::<SpawnJob>()
+            .add_event::<VacancyDirty>()
+            .add_systems(Update, enrol_compulsory);
+    }
+}
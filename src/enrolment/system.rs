@@ -0,0 +1,197 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::enrolment::component::Waitlist;
+use crate::enrolment::config::EnrolmentConfig;
+use crate::enrolment::events::Enrolled;
+use crate::gregslist::component::VacancyDirty;
+use crate::hiring_manager::component::{Age, Unemployed};
+use crate::jobs::{Employment, Job, JobCatalog, JobType, SpawnJob};
+use crate::person::{AgingConfig, Person, Position};
+use crate::personality::Personality;
+
+// Distance between two sites; unknown sites count as next door.
+fn distance(a: Option<&Position>, b: Option<&Position>) -> f32 {
+    match (a, b) {
+        (Some(a), Some(b)) => a.distance(b),
+        _ => 0.0,
+    }
+}
+
+/// Every `interval_secs`, place everyone without a seat whom a compulsory
+/// role admits. Each goes to the nearest institution with room, waitlisted
+/// people first; the rest wait at the nearest institution that admits them.
+/// When `open_at` people wait for a catalog job type, one more is opened
+/// amid them.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn enrol_compulsory(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<EnrolmentConfig>,
+    catalog: Option<Res<JobCatalog>>,
+    mut last: Local<Option<f64>>,
+    mut institutions: Query<(
+        Entity,
+        &mut Job,
+        Option<&Position>,
+        Option<&JobType>,
+        Option<&mut Waitlist>,
+    )>,
+    people: Query<
+        EntityRef,
+        (
+            Without<Employment>,
+            Without<Job>,
+            Or<(With<Person>, With<Age>)>,
+        ),
+    >,
+    opened: Query<&JobType, Added<JobType>>,
+    mut opening: Local<HashSet<String>>,
+    mut enrolled: EventWriter<Enrolled>,
+    mut spawn: EventWriter<SpawnJob>,
+    mut dirty: EventWriter<VacancyDirty>,
+) {
+    // read every frame, or openings between passes would be missed
+    for t in opened.iter() {
+        opening.remove(&t.0);
+    }
+    let now = time.elapsed_secs_f64();
+    if last.is_some_and(|t| now - t < cfg.interval_secs) {
+        return;
+    }
+    *last = Some(now);
+
+    // (institution, role_index) -> free seats
+    let mut seats: BTreeMap<(Entity, usize), u32> = BTreeMap::new();
+    for (e, job, ..) in institutions.iter() {
+        for (i, (spec, members)) in job.roles.iter().enumerate() {
+            if spec.compulsory {
+                seats.insert((e, i), spec.max.saturating_sub(members.len() as u32));
+            }
+        }
+    }
+    if seats.is_empty() {
+        return;
+    }
+    let mut order: Vec<Entity> = seats.keys().map(|&(e, _)| e).collect();
+    order.dedup();
+    let queued: Vec<Entity> = order
+        .iter()
+        .filter_map(|&e| institutions.get(e).ok()?.4)
+        .flat_map(|w| w.0.iter().map(|&(_, p)| p))
+        .collect();
+
+    let mut seen: HashSet<Entity> = HashSet::new();
+    let mut candidates: Vec<Entity> = queued.into_iter().filter(|&p| seen.insert(p)).collect();
+    let mut rest: Vec<Entity> = people
+        .iter()
+        .map(|p| p.id())
+        .filter(|p| !seen.contains(p))
+        .collect();
+    rest.sort();
+    candidates.extend(rest);
+
+    let mut waiting: HashMap<Entity, Vec<(usize, Entity)>> = HashMap::new();
+    let mut placed: HashSet<Entity> = HashSet::new();
+    for person in candidates {
+        let Ok(entity) = people.get(person) else {
+            continue;
+        };
+        let home = entity.get::<Position>();
+        // nearest admitting seat with room, and nearest admitting seat at all
+        let mut open: Option<(f32, Entity, usize)> = None;
+        let mut any: Option<(f32, Entity, usize)> = None;
+        for (&(e, i), &free) in seats.iter() {
+            let Ok((_, job, site, _, _)) = institutions.get(e) else {
+                continue;
+            };
+            if job.roles[i].0.admits(&entity).is_err() {
+                continue;
+            }
+            let d = distance(home, site);
+            if any.is_none_or(|(best, ..)| d < best) {
+                any = Some((d, e, i));
+            }
+            if free > 0 && open.is_none_or(|(best, ..)| d < best) {
+                open = Some((d, e, i));
+            }
+        }
+        if let Some((_, e, i)) = open {
+            let Ok((_, mut job, ..)) = institutions.get_mut(e) else {
+                continue;
+            };
+            let (spec, members) = &mut job.roles[i];
+            members.push(person);
+            *seats.get_mut(&(e, i)).unwrap() -= 1;
+            commands
+                .entity(person)
+                .remove::<Unemployed>()
+                .insert(Employment {
+                    since: aging.years(now),
+                    fit: spec.fit(entity.get::<Personality>()),
+                    ..Employment::new(e, i)
+                });
+            enrolled.write(Enrolled {
+                person,
+                job: e,
+                role_index: i,
+            });
+            placed.insert(e);
+        } else if let Some((_, e, i)) = any {
+            waiting.entry(e).or_default().push((i, person));
+        }
+    }
+    for &e in &placed {
+        dirty.write(VacancyDirty { job: e });
+    }
+
+    // job type -> (people waiting, sum of their positions, how many have one)
+    let mut demand: BTreeMap<String, (usize, f32, f32, usize)> = BTreeMap::new();
+    for e in order {
+        let Ok((_, _, _, job_type, waitlist)) = institutions.get_mut(e) else {
+            continue;
+        };
+        let queue = Waitlist(waiting.remove(&e).unwrap_or_default());
+        if let Some(t) = job_type
+            && !queue.0.is_empty()
+        {
+            let d = demand.entry(t.0.clone()).or_default();
+            d.0 += queue.0.len();
+            for pos in queue
+                .0
+                .iter()
+                .filter_map(|&(_, p)| people.get(p).ok()?.get::<Position>().copied())
+            {
+                d.1 += pos.x;
+                d.2 += pos.y;
+                d.3 += 1;
+            }
+        }
+        match waitlist {
+            Some(mut w) if *w != queue => *w = queue,
+            Some(_) => {}
+            None if !queue.0.is_empty() => {
+                commands.entity(e).insert(queue);
+            }
+            None => {}
+        }
+    }
+
+    let Some(open_at) = cfg.open_at else {
+        return;
+    };
+    for (name, (count, x, y, sited)) in demand {
+        // a type the catalog cannot spawn would stay "opening" forever
+        let known = catalog.as_ref().is_some_and(|c| c.get(&name).is_some());
+        if count < open_at.max(1) || !known || !opening.insert(name.clone()) {
+            continue;
+        }
+        let mut req = SpawnJob::new(name, 1);
+        if sited > 0 {
+            req = req.at(Position::new(x / sited as f32, y / sited as f32));
+        }
+        spawn.write(req);
+    }
+}
@@ -0,0 +1,30 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    /// Infected but not yet infectious (skipped when `latent_days` is 0).
+    Exposed,
+    Infectious,
+    /// Immune until `days_left` runs out, then susceptible again.
+    Recovered,
+}
+
+/// Where an infection was caught.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Channel {
+    Seed,
+    Household,
+    Workplace,
+    Social,
+}
+
+/// Disease state of a person; people without it are susceptible.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Infection {
+    pub stage: Stage,
+    /// Simulated days until the next stage.
+    pub days_left: f64,
+    pub infected_by: Option<Entity>,
+    /// People this one has infected so far.
+    pub secondary: u32,
+}
@@ -0,0 +1,42 @@
+use bevy_ecs::prelude::*;
+
+/// SEIR parameters. Durations are means of exponential draws, in simulated
+/// days; transmission rates are per infectious contact per day.
+#[derive(Resource, Clone, Debug)]
+pub struct EpidemicConfig {
+    /// Random people infected once the population exists.
+    pub initial_infections: usize,
+    /// 0 gives SIR: new infections are infectious at once.
+    pub latent_days: f64,
+    pub infectious_days: f64,
+    /// `None` means recovery gives lifelong immunity.
+    pub immunity_days: Option<f64>,
+    /// Between parents and their children below `adult_age`, and among those children.
+    pub household_beta: f64,
+    /// Between members of the same `Job` who are not `Absent`.
+    pub workplace_beta: f64,
+    /// Along `Graph<Tie>` edges, scaled by tie strength.
+    pub social_beta: f64,
+    pub adult_age: f32,
+    /// Chance that a newly infectious person stays away from their job.
+    pub absence_prob: f64,
+    /// `HazardMultiplier` while infectious.
+    pub hazard_multiplier: f64,
+}
+
+impl Default for EpidemicConfig {
+    fn default() -> Self {
+        Self {
+            initial_infections: 0,
+            latent_days: 3.0,
+            infectious_days: 7.0,
+            immunity_days: Some(180.0),
+            household_beta: 0.3,
+            workplace_beta: 0.05,
+            social_beta: 0.1,
+            adult_age: 18.0,
+            absence_prob: 0.7,
+            hazard_multiplier: 2.0,
+        }
+    }
+}
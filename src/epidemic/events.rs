@@ -0,0 +1,18 @@
+use bevy_ecs::prelude::*;
+
+use crate::epidemic::component::Channel;
+
+/// `entity` caught the disease.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Infected {
+    pub entity: Entity,
+    pub by: Option<Entity>,
+    pub channel: Channel,
+}
+
+/// `entity` stopped being infectious after infecting `secondary` others.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct InfectionEnded {
+    pub entity: Entity,
+    pub secondary: u32,
+}
@@ -0,0 +1,10 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::{Channel, Infection, Stage};
+pub use config::EpidemicConfig;
+pub use events::{Infected, InfectionEnded};
+pub use plugin::EpidemicPlugin;
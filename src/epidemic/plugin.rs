@@ -0,0 +1,26 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::epidemic::config::EpidemicConfig;
+use crate::epidemic::events::{Infected, InfectionEnded};
+use crate::epidemic::system::{progress_infections, seed_infections, transmit};
+use crate::person::AgingConfig;
+
+/// SEIR disease spreading through households, workplaces and `Graph<Tie>`
+/// contacts. Infectious people carry a `HazardMultiplier` and may be `Absent`.
+pub struct EpidemicPlugin;
+
+impl Plugin for EpidemicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EpidemicConfig>()
+            .init_resource::<AgingConfig>()
+            .init_resource::<GameRNG>()
+            .add_event::<Infected>()
+            .add_event::<InfectionEnded>()
+            .add_systems(
+                Update,
+                (seed_infections, progress_infections, transmit).chain(),
+            );
+    }
+}
@@ -0,0 +1,253 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::Rng;
+use rand::seq::index;
+use rand_distr::{Distribution, Exp};
+use std::collections::HashMap;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::epidemic::component::{Channel, Infection, Stage};
+use crate::epidemic::config::EpidemicConfig;
+use crate::epidemic::events::{Infected, InfectionEnded};
+use crate::graph::Graph;
+use crate::jobs::{Absent, Job};
+use crate::mortality::HazardMultiplier;
+use crate::person::{AgingConfig, Parents, Person};
+use crate::social::Tie;
+
+const DAYS_PER_YEAR: f64 = 365.0;
+
+fn duration<R: Rng + ?Sized>(mean_days: f64, rng: &mut R) -> f64 {
+    match Exp::new(1.0 / mean_days) {
+        Ok(d) if mean_days > 0.0 => d.sample(rng),
+        _ => 0.0,
+    }
+}
+
+// Start a new infection, straight to infectious under SIR.
+fn infect<R: Rng + ?Sized>(
+    commands: &mut Commands,
+    cfg: &EpidemicConfig,
+    rng: &mut R,
+    e: Entity,
+    by: Option<Entity>,
+) {
+    let mut infection = Infection {
+        stage: Stage::Exposed,
+        days_left: duration(cfg.latent_days, rng),
+        infected_by: by,
+        secondary: 0,
+    };
+    if cfg.latent_days <= 0.0 {
+        become_infectious(commands, cfg, rng, e, &mut infection);
+    }
+    commands.entity(e).insert(infection);
+}
+
+fn become_infectious<R: Rng + ?Sized>(
+    commands: &mut Commands,
+    cfg: &EpidemicConfig,
+    rng: &mut R,
+    e: Entity,
+    infection: &mut Infection,
+) {
+    infection.stage = Stage::Infectious;
+    infection.days_left += duration(cfg.infectious_days, rng);
+    let mut ec = commands.entity(e);
+    ec.insert(HazardMultiplier(cfg.hazard_multiplier));
+    if rng.random_bool(cfg.absence_prob.clamp(0.0, 1.0)) {
+        ec.insert(Absent);
+    }
+}
+
+/// Infect `initial_infections` random people once the population exists.
+pub fn seed_infections(
+    mut commands: Commands,
+    cfg: Res<EpidemicConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut done: Local<bool>,
+    people: Query<Entity, (With<Person>, Without<Infection>)>,
+    mut infected: EventWriter<Infected>,
+) {
+    if *done {
+        return;
+    }
+    let mut pool: Vec<Entity> = people.iter().collect();
+    if pool.is_empty() {
+        return;
+    }
+    *done = true;
+    pool.sort();
+    let k = cfg.initial_infections.min(pool.len());
+    for i in index::sample(&mut rng.0, pool.len(), k) {
+        infect(&mut commands, &cfg, &mut rng.0, pool[i], None);
+        infected.write(Infected {
+            entity: pool[i],
+            by: None,
+            channel: Channel::Seed,
+        });
+    }
+}
+
+/// Advance E -> I -> R -> S as the stage timers run out.
+pub fn progress_infections(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<EpidemicConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut cases: Query<(Entity, &mut Infection)>,
+    mut ended: EventWriter<InfectionEnded>,
+) {
+    let dt = time.delta_secs_f64() * aging.years_per_sec * DAYS_PER_YEAR;
+    if dt <= 0.0 {
+        return;
+    }
+    let mut order: Vec<Entity> = cases.iter().map(|(e, _)| e).collect();
+    order.sort();
+    for e in order {
+        let Ok((_, mut infection)) = cases.get_mut(e) else {
+            continue;
+        };
+        infection.days_left -= dt;
+        while infection.days_left <= 0.0 {
+            match infection.stage {
+                Stage::Exposed => {
+                    become_infectious(&mut commands, &cfg, &mut rng.0, e, &mut infection);
+                }
+                Stage::Infectious => {
+                    ended.write(InfectionEnded {
+                        entity: e,
+                        secondary: infection.secondary,
+                    });
+                    commands
+                        .entity(e)
+                        .remove::<HazardMultiplier>()
+                        .remove::<Absent>();
+                    infection.stage = Stage::Recovered;
+                    infection.days_left += cfg
+                        .immunity_days
+                        .map_or(f64::INFINITY, |d| duration(d, &mut rng.0));
+                }
+                Stage::Recovered => {
+                    commands.entity(e).remove::<Infection>();
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Every susceptible person in contact with infectious people catches the
+/// disease with probability `1 - exp(-rate * dt)`, the rate summed over
+/// household, workplace and social contacts. The infector is drawn in
+/// proportion to their share of the rate.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn transmit(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<EpidemicConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut cases: Query<(Entity, &mut Infection)>,
+    susceptible: Query<(), (With<Person>, Without<Infection>)>,
+    families: Query<(Entity, &Person, &Parents)>,
+    jobs: Query<&Job>,
+    absent: Query<(), With<Absent>>,
+    contacts: Query<&Graph<Tie>>,
+    mut infected: EventWriter<Infected>,
+) {
+    let dt = time.delta_secs_f64() * aging.years_per_sec * DAYS_PER_YEAR;
+    if dt <= 0.0 {
+        return;
+    }
+    let mut sources: Vec<Entity> = cases
+        .iter()
+        .filter(|(_, i)| i.stage == Stage::Infectious)
+        .map(|(e, _)| e)
+        .collect();
+    if sources.is_empty() {
+        return;
+    }
+    sources.sort();
+
+    // target -> [(source, rate, channel)]
+    let mut pressure: HashMap<Entity, Vec<(Entity, f64, Channel)>> = HashMap::new();
+    let mut expose = |from: Entity, to: Entity, rate: f64, channel: Channel| {
+        if from != to && rate > 0.0 && susceptible.contains(to) {
+            pressure.entry(to).or_default().push((from, rate, channel));
+        }
+    };
+    let is_source = |e: Entity| sources.binary_search(&e).is_ok();
+
+    // households: parents with their minor children
+    let mut households: HashMap<(Entity, Entity), Vec<Entity>> = HashMap::new();
+    for (child, person, &Parents(a, b)) in families.iter() {
+        if person.age < cfg.adult_age {
+            let home = households.entry((a.min(b), a.max(b))).or_insert_with(|| vec![a, b]);
+            home.push(child);
+        }
+    }
+    let mut homes: Vec<Vec<Entity>> = households.into_values().collect();
+    for home in homes.iter_mut() {
+        home.sort();
+    }
+    homes.sort();
+    for home in &homes {
+        for &from in home.iter().filter(|&&e| is_source(e)) {
+            for &to in home {
+                expose(from, to, cfg.household_beta, Channel::Household);
+            }
+        }
+    }
+
+    for job in jobs.iter() {
+        let present: Vec<Entity> = job
+            .roles
+            .iter()
+            .flat_map(|(_, members)| members.iter().copied())
+            .filter(|&e| !absent.contains(e))
+            .collect();
+        for &from in present.iter().filter(|&&e| is_source(e)) {
+            for &to in &present {
+                expose(from, to, cfg.workplace_beta, Channel::Workplace);
+            }
+        }
+    }
+
+    for &from in &sources {
+        if let Ok(g) = contacts.get(from) {
+            for (to, tie) in g.iter() {
+                expose(from, to, cfg.social_beta * tie.strength as f64, Channel::Social);
+            }
+        }
+    }
+
+    let mut targets: Vec<(Entity, Vec<(Entity, f64, Channel)>)> = pressure.into_iter().collect();
+    targets.sort_by_key(|(e, _)| *e);
+    for (to, mut exposures) in targets {
+        exposures.sort_by_key(|&(from, _, c)| (from, c as u8));
+        let rate: f64 = exposures.iter().map(|&(_, r, _)| r).sum();
+        if !rng.0.random_bool((1.0 - (-rate * dt).exp()).clamp(0.0, 1.0)) {
+            continue;
+        }
+        let mut u = rng.0.random::<f64>() * rate;
+        let &(from, _, channel) = exposures
+            .iter()
+            .find(|&&(_, r, _)| {
+                u -= r;
+                u <= 0.0
+            })
+            .unwrap_or(exposures.last().unwrap());
+
+        infect(&mut commands, &cfg, &mut rng.0, to, Some(from));
+        if let Ok((_, mut source)) = cases.get_mut(from) {
+            source.secondary += 1;
+        }
+        infected.write(Infected {
+            entity: to,
+            by: Some(from),
+            channel,
+        });
+    }
+}
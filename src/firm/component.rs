@@ -0,0 +1,30 @@
+use bevy_ecs::prelude::*;
+
+/// A business running jobs of one catalog type. It pays their wages from
+/// its own `Account` (each job names it as `Payer`) and keeps the takings.
+#[derive(Component, Debug, Clone)]
+pub struct Firm {
+    pub job_type: String,
+    pub jobs: Vec<Entity>,
+}
+
+/// Takings and wages owed since the firm's last review.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct Ledger {
+    pub revenue: f64,
+    /// Every wage owed, paid or not.
+    pub costs: f64,
+    /// The part of `costs` the firm could not pay.
+    pub arrears: f64,
+}
+
+impl Ledger {
+    pub fn profit(&self) -> f64 {
+        self.revenue - self.costs
+    }
+
+    /// Whether the firm missed any payroll since its last review.
+    pub fn insolvent(&self) -> bool {
+        self.arrears > 0.0
+    }
+}
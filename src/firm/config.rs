@@ -0,0 +1,32 @@
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+#[derive(Resource, Clone, Debug)]
+pub struct FirmConfig {
+    /// Simulated days between reviews of every firm's books.
+    pub review_days: f64,
+    /// What the market pays per item, by kind; unpriced items are kept.
+    pub prices: HashMap<String, f64>,
+    /// Balance a new firm's `Account` opens with.
+    pub founding_funds: f64,
+    /// Seats a profitable firm adds to each of its full roles per review.
+    pub seats_per_review: u32,
+    /// A firm opens another job while there are more than this many people
+    /// per job it runs; `None` ignores population.
+    pub people_per_job: Option<f64>,
+    /// Most jobs one firm runs.
+    pub max_jobs: usize,
+}
+
+impl Default for FirmConfig {
+    fn default() -> Self {
+        Self {
+            review_days: 365.0,
+            prices: HashMap::from([("grain".to_string(), 500.0)]),
+            founding_funds: 100_000.0,
+            seats_per_review: 1,
+            people_per_job: Some(500.0),
+            max_jobs: 10,
+        }
+    }
+}
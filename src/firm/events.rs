@@ -0,0 +1,29 @@
+use bevy_ecs::prelude::*;
+
+/// Start a firm running one job of catalog type `job_type`.
+#[derive(Event, Debug, Clone)]
+pub struct FoundFirm {
+    pub job_type: String,
+}
+
+impl FoundFirm {
+    pub fn new(job_type: impl Into<String>) -> Self {
+        Self {
+            job_type: job_type.into(),
+        }
+    }
+}
+
+/// `firm` started running `job`.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct JobOpened {
+    pub firm: Entity,
+    pub job: Entity,
+}
+
+/// `firm` went bust. Its `jobs` are gone and their members `Unemployed`.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct FirmClosed {
+    pub firm: Entity,
+    pub jobs: Vec<Entity>,
+}
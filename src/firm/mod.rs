@@ -0,0 +1,11 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::{Firm, Ledger};
+pub use config::FirmConfig;
+pub use events::{FirmClosed, FoundFirm, JobOpened};
+pub use plugin::FirmPlugin;
+pub use system::{book_wages, found_firms, review_firms, sell_output};
@@ -0,0 +1,55 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::economy::Payday;
+use crate::firm::config::FirmConfig;
+use crate::firm::events::{FirmClosed, FoundFirm, JobOpened};
+use crate::firm::system::{book_wages, found_firms, review_firms, sell_output};
+use crate::gregslist::component::VacancyDirty;
+use crate::separation::Separated;
+use crate::person::AgingConfig;
+use crate::personality::LifeEvent;
+
+/// Firms own jobs from the `JobCatalog`: they sell what their jobs make,
+/// pay their wages, grow when profitable or when the population outgrows
+/// them, and close when insolvent, laying everyone off.
+#[derive(Default)]
+pub struct FirmPlugin {
+    found: Vec<String>,
+}
+
+impl FirmPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Found a firm running job type `job_type` at startup.
+    pub fn found(mut self, job_type: impl Into<String>) -> Self {
+        self.found.push(job_type.into());
+        self
+    }
+}
+
+impl Plugin for FirmPlugin {
+    fn build(&self, app: &mut App) {
+        let found = self.found.clone();
+        app.init_resource::<FirmConfig>()
+            .init_resource::<AgingConfig>()
+            .add_event::<FoundFirm>()
+            .add_event::<JobOpened>()
+            .add_event::<FirmClosed>()
+            .add_event::<Payday>()
+            .add_event::<VacancyDirty>()
+            .add_event::<Separated>()
+            .add_event::<LifeEvent>()
+            .add_systems(Startup, move |mut out: EventWriter<FoundFirm>| {
+                for job_type in &found {
+                    out.write(FoundFirm::new(job_type.clone()));
+                }
+            })
+            .add_systems(
+                Update,
+                (found_firms, sell_output, book_wages, review_firms).chain(),
+            );
+    }
+}
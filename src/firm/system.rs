@@ -0,0 +1,217 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use crate::economy::{Account, Payday, Payer};
+use crate::firm::component::{Firm, Ledger};
+use crate::firm::config::FirmConfig;
+use crate::firm::events::{FirmClosed, FoundFirm, JobOpened};
+use crate::gregslist::component::VacancyDirty;
+use crate::inventory::component::{Inventory, Item};
+use crate::jobs::{Job, JobCatalog, JobType};
+use crate::person::{AgingConfig, Person};
+use crate::personality::LifeEvent;
+use crate::separation::{release, Separated, SeparationReason};
+
+const DAYS_PER_YEAR: f64 = 365.0;
+
+// Spawn a job of the firm's type, paid for by the firm. Without a catalog
+// there is nothing to spawn.
+fn open_job(
+    commands: &mut Commands,
+    catalog: Option<&JobCatalog>,
+    firm_entity: Entity,
+    firm: &mut Firm,
+    opened: &mut EventWriter<JobOpened>,
+) -> Option<Entity> {
+    let Some(job) = catalog.and_then(|c| c.instantiate(&firm.job_type)) else {
+        bevy_log::error!("no job type {:?} in the catalog", firm.job_type);
+        return None;
+    };
+    let job = commands
+        .spawn((job, JobType(firm.job_type.clone()), Payer(firm_entity)))
+        .id();
+    firm.jobs.push(job);
+    opened.write(JobOpened {
+        firm: firm_entity,
+        job,
+    });
+    Some(job)
+}
+
+/// Start requested firms, each with the founding funds and one job.
+pub fn found_firms(
+    mut commands: Commands,
+    cfg: Res<FirmConfig>,
+    catalog: Option<Res<JobCatalog>>,
+    mut requests: EventReader<FoundFirm>,
+    mut opened: EventWriter<JobOpened>,
+) {
+    for req in requests.read() {
+        let firm_entity = commands.spawn_empty().id();
+        let mut firm = Firm {
+            job_type: req.job_type.clone(),
+            jobs: Vec::new(),
+        };
+        let catalog = catalog.as_deref();
+        if open_job(&mut commands, catalog, firm_entity, &mut firm, &mut opened).is_none() {
+            commands.entity(firm_entity).despawn();
+            continue;
+        }
+        commands.entity(firm_entity).insert((
+            firm,
+            Account::new(cfg.founding_funds),
+            Ledger::default(),
+        ));
+    }
+}
+
+/// Sell every priced item in the firms' job inventories to the market.
+pub fn sell_output(
+    mut commands: Commands,
+    cfg: Res<FirmConfig>,
+    mut firms: Query<(&Firm, &mut Account, &mut Ledger)>,
+    mut inventories: Query<&mut Inventory>,
+    items: Query<&Item>,
+) {
+    let price = |item: Entity| {
+        items
+            .get(item)
+            .ok()
+            .and_then(|it| cfg.prices.get(&it.kind).copied())
+    };
+    for (firm, mut account, mut ledger) in firms.iter_mut() {
+        for &job in &firm.jobs {
+            let Ok(inv) = inventories.get(job) else {
+                continue;
+            };
+            if !inv.items.iter().any(|&item| price(item).is_some()) {
+                continue;
+            }
+            let Ok(mut inv) = inventories.get_mut(job) else {
+                continue;
+            };
+            let mut takings = 0.0;
+            inv.items.retain(|&item| match price(item) {
+                Some(p) => {
+                    takings += p;
+                    commands.entity(item).despawn();
+                    false
+                }
+                None => true,
+            });
+            account.deposit(takings);
+            ledger.revenue += takings;
+        }
+    }
+}
+
+/// Charge the wages each firm owed to its `Ledger`, noting any it could
+/// not pay as arrears.
+pub fn book_wages(mut paydays: EventReader<Payday>, mut ledgers: Query<&mut Ledger>) {
+    for payday in paydays.read() {
+        for &(holder, amount) in &payday.spent {
+            if let Ok(mut ledger) = ledgers.get_mut(holder) {
+                ledger.costs += amount;
+            }
+        }
+        for &(holder, amount) in &payday.unpaid {
+            if let Ok(mut ledger) = ledgers.get_mut(holder) {
+                ledger.costs += amount;
+                ledger.arrears += amount;
+            }
+        }
+    }
+}
+
+/// Every `review_days`, close firms that missed a payroll or are out of
+/// money and losing it, laying off their staff. Profitable firms add seats
+/// to full roles, and any firm opens another job while the population
+/// outgrows its jobs.
+#[allow(clippy::too_many_arguments)]
+pub fn review_firms(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<FirmConfig>,
+    catalog: Option<Res<JobCatalog>>,
+    mut days: Local<f64>,
+    mut firms: Query<(Entity, &mut Firm, &Account, &mut Ledger)>,
+    mut jobs: Query<(&mut Job, Option<&Inventory>)>,
+    people: Query<(), With<Person>>,
+    mut opened: EventWriter<JobOpened>,
+    mut closed: EventWriter<FirmClosed>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut separated: EventWriter<Separated>,
+    mut life: EventWriter<LifeEvent>,
+) {
+    *days += time.delta_secs_f64() * aging.years_per_sec * DAYS_PER_YEAR;
+    if cfg.review_days <= 0.0 || *days < cfg.review_days {
+        return;
+    }
+    *days -= cfg.review_days;
+    let population = people.iter().count() as f64;
+
+    let mut order: Vec<Entity> = firms.iter().map(|(e, ..)| e).collect();
+    order.sort();
+    for e in order {
+        let Ok((_, mut firm, account, mut ledger)) = firms.get_mut(e) else {
+            continue;
+        };
+        firm.jobs.retain(|&j| jobs.contains(j));
+        let books = std::mem::take(&mut *ledger);
+
+        if books.insolvent() || (account.balance <= 0.0 && books.profit() < 0.0) {
+            for &j in &firm.jobs {
+                let Ok((job, inventory)) = jobs.get(j) else {
+                    continue;
+                };
+                for (i, m) in job.members() {
+                    release(
+                        &mut commands,
+                        j,
+                        i,
+                        m,
+                        SeparationReason::LaidOff,
+                        &mut separated,
+                        &mut life,
+                    );
+                }
+                for &item in inventory.map_or(&[][..], |inv| inv.items.as_slice()) {
+                    commands.entity(item).despawn();
+                }
+                commands.entity(j).despawn();
+            }
+            commands.entity(e).despawn();
+            closed.write(FirmClosed {
+                firm: e,
+                jobs: std::mem::take(&mut firm.jobs),
+            });
+            continue;
+        }
+
+        if books.profit() > 0.0 && cfg.seats_per_review > 0 {
+            for &j in &firm.jobs {
+                let Ok((mut job, _)) = jobs.get_mut(j) else {
+                    continue;
+                };
+                let mut grew = false;
+                for (spec, members) in job.roles.iter_mut() {
+                    if members.len() as u32 >= spec.max {
+                        spec.max += cfg.seats_per_review;
+                        grew = true;
+                    }
+                }
+                if grew {
+                    dirty.write(VacancyDirty { job: j });
+                }
+            }
+        }
+
+        let crowded = cfg
+            .people_per_job
+            .is_some_and(|n| population > n * firm.jobs.len() as f64);
+        if crowded && firm.jobs.len() < cfg.max_jobs {
+            open_job(&mut commands, catalog.as_deref(), e, &mut firm, &mut opened);
+        }
+    }
+}
@@ -1,6 +1,33 @@
 use bevy_ecs::prelude::*;
 use std::collections::HashMap;
 
+/// Numeric weight of an edge value, used by `Network` and exports.
+pub trait EdgeWeight {
+    fn weight(&self) -> f64;
+}
+
+impl EdgeWeight for f32 {
+    fn weight(&self) -> f64 {
+        *self as f64
+    }
+}
+
+impl EdgeWeight for f64 {
+    fn weight(&self) -> f64 {
+        *self
+    }
+}
+
+/// Unweighted edges count as 1.
+impl EdgeWeight for () {
+    fn weight(&self) -> f64 {
+        1.0
+    }
+}
+
+/// Outgoing edges of the entity that owns this component, keyed by target.
+/// Add `RelationshipPlugin::<V>` to keep edges symmetric and free of
+/// despawned targets.
 #[derive(Component, Debug)]
 pub struct Graph<V> {
     pub edges: HashMap<Entity, V>,
@@ -41,5 +68,20 @@ impl<V> Graph<V> {
     pub fn remove(&mut self, to: &Entity) -> Option<V> {
         self.edges.remove(to)
     }
-}
 
+    pub fn contains(&self, to: &Entity) -> bool {
+        self.edges.contains_key(to)
+    }
+
+    pub fn neighbours(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.edges.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &V)> {
+        self.edges.iter().map(|(&e, v)| (e, v))
+    }
+
+    pub fn degree(&self) -> usize {
+        self.edges.len()
+    }
+}
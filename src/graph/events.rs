@@ -0,0 +1,40 @@
+use bevy_ecs::prelude::*;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+
+use crate::graph::export::GraphFormat;
+
+/// An edge `from -> to` appeared in a `Graph<V>`.
+#[derive(Event, Debug, Clone)]
+pub struct EdgeAdded<V: Send + Sync + 'static> {
+    pub from: Entity,
+    pub to: Entity,
+    pub value: V,
+}
+
+/// An edge `from -> to` disappeared, either removed directly, mirrored from
+/// the other endpoint, or because an endpoint was despawned.
+#[derive(Event, Debug, Clone)]
+pub struct EdgeRemoved<V: Send + Sync + 'static> {
+    pub from: Entity,
+    pub to: Entity,
+    pub value: V,
+}
+
+/// Ask `GraphExportPlugin<V>` to write the current `Graph<V>` network to `path`.
+#[derive(Event, Debug, Clone)]
+pub struct ExportGraph<V: Send + Sync + 'static> {
+    pub path: PathBuf,
+    pub format: GraphFormat,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V: Send + Sync + 'static> ExportGraph<V> {
+    pub fn new(path: impl Into<PathBuf>, format: GraphFormat) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            _marker: PhantomData,
+        }
+    }
+}
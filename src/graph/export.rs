@@ -0,0 +1,301 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::graph::component::{EdgeWeight, Graph};
+use crate::graph::events::ExportGraph;
+use crate::graph::system::RelationshipConfig;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GraphFormat {
+    GraphMl,
+    Dot,
+}
+
+impl GraphFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            GraphFormat::GraphMl => "graphml",
+            GraphFormat::Dot => "dot",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum AttrValue {
+    Float(f64),
+    Int(i64),
+    Bool(bool),
+    Text(String),
+}
+
+impl AttrValue {
+    fn graphml_type(&self) -> &'static str {
+        match self {
+            AttrValue::Float(_) => "double",
+            AttrValue::Int(_) => "long",
+            AttrValue::Bool(_) => "boolean",
+            AttrValue::Text(_) => "string",
+        }
+    }
+}
+
+impl std::fmt::Display for AttrValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttrValue::Float(v) => write!(f, "{v}"),
+            AttrValue::Int(v) => write!(f, "{v}"),
+            AttrValue::Bool(v) => write!(f, "{v}"),
+            AttrValue::Text(v) => f.write_str(v),
+        }
+    }
+}
+
+/// Snapshot of one `Graph<V>` network ready to be written out. Nodes are
+/// every graph owner and edge target, ordered by `Entity`; an undirected
+/// network lists each edge once.
+#[derive(Clone, Debug, Default)]
+pub struct GraphExport {
+    pub directed: bool,
+    pub nodes: Vec<(Entity, BTreeMap<String, AttrValue>)>,
+    pub edges: Vec<(Entity, Entity, f64)>,
+}
+
+/// Attributes written for each node. The graph knows nothing about what its
+/// nodes are, so the caller describes them.
+pub type NodeAttributes = Arc<dyn Fn(EntityRef) -> BTreeMap<String, AttrValue> + Send + Sync>;
+
+impl GraphExport {
+    /// Snapshot every `Graph<V>` in the world, with `attributes` on each node
+    /// that still exists. Direction follows `RelationshipConfig<V>` when present.
+    pub fn from_world<V: EdgeWeight + Send + Sync + 'static>(
+        world: &mut World,
+        attributes: impl Fn(EntityRef) -> BTreeMap<String, AttrValue>,
+    ) -> Self {
+        let directed = world
+            .get_resource::<RelationshipConfig<V>>()
+            .is_none_or(|c| !c.undirected);
+        let mut q = world.query::<(Entity, &Graph<V>)>();
+        let mut nodes: Vec<Entity> = Vec::new();
+        let mut edges: Vec<(Entity, Entity, f64)> = Vec::new();
+        for (from, g) in q.iter(world) {
+            nodes.push(from);
+            for (to, v) in g.iter() {
+                nodes.push(to);
+                edges.push((from, to, v.weight()));
+            }
+        }
+        edges.sort_by_key(|&(a, b, _)| (a, b));
+        if !directed {
+            // mirrored pairs: keep the lower -> higher copy
+            let mut seen = std::collections::HashSet::new();
+            edges.retain(|&(a, b, _)| seen.insert((a.min(b), a.max(b))));
+        }
+        nodes.sort();
+        nodes.dedup();
+        let world: &World = world;
+        Self {
+            directed,
+            nodes: nodes
+                .into_iter()
+                .map(|e| {
+                    let attrs = world.get_entity(e).map(&attributes).unwrap_or_default();
+                    (e, attrs)
+                })
+                .collect(),
+            edges,
+        }
+    }
+
+    /// Attribute names and their GraphML types, first value seen wins.
+    fn keys(&self) -> BTreeMap<&str, &'static str> {
+        let mut keys = BTreeMap::new();
+        for (_, attrs) in &self.nodes {
+            for (k, v) in attrs {
+                keys.entry(k.as_str()).or_insert(v.graphml_type());
+            }
+        }
+        keys
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let mut s = String::new();
+        s.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        s.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (k, ty) in self.keys() {
+            let k = xml_escape(k);
+            let _ = writeln!(
+                s,
+                "  <key id=\"{k}\" for=\"node\" attr.name=\"{k}\" attr.type=\"{ty}\"/>"
+            );
+        }
+        s.push_str(
+            "  <key id=\"weight\" for=\"edge\" attr.name=\"weight\" attr.type=\"double\"/>\n",
+        );
+        let kind = if self.directed {
+            "directed"
+        } else {
+            "undirected"
+        };
+        let _ = writeln!(s, "  <graph id=\"G\" edgedefault=\"{kind}\">");
+        for (e, attrs) in &self.nodes {
+            if attrs.is_empty() {
+                let _ = writeln!(s, "    <node id=\"{}\"/>", node_id(*e));
+                continue;
+            }
+            let _ = writeln!(s, "    <node id=\"{}\">", node_id(*e));
+            for (k, v) in attrs {
+                let _ = writeln!(
+                    s,
+                    "      <data key=\"{}\">{}</data>",
+                    xml_escape(k),
+                    xml_escape(&v.to_string())
+                );
+            }
+            s.push_str("    </node>\n");
+        }
+        for (a, b, w) in &self.edges {
+            let _ = writeln!(
+                s,
+                "    <edge source=\"{}\" target=\"{}\"><data key=\"weight\">{w}</data></edge>",
+                node_id(*a),
+                node_id(*b)
+            );
+        }
+        s.push_str("  </graph>\n</graphml>\n");
+        s
+    }
+
+    pub fn to_dot(&self) -> String {
+        let mut s = String::new();
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        let _ = writeln!(s, "{kind} G {{");
+        for (e, attrs) in &self.nodes {
+            let list: Vec<String> = attrs
+                .iter()
+                .map(|(k, v)| match v {
+                    AttrValue::Text(t) => format!("{k}=\"{}\"", t.replace('"', "\\\"")),
+                    _ => format!("{k}={v}"),
+                })
+                .collect();
+            if list.is_empty() {
+                let _ = writeln!(s, "  {};", node_id(*e));
+            } else {
+                let _ = writeln!(s, "  {} [{}];", node_id(*e), list.join(", "));
+            }
+        }
+        for (a, b, w) in &self.edges {
+            let _ = writeln!(s, "  {} {arrow} {} [weight={w}];", node_id(*a), node_id(*b));
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    pub fn render(&self, format: GraphFormat) -> String {
+        match format {
+            GraphFormat::GraphMl => self.to_graphml(),
+            GraphFormat::Dot => self.to_dot(),
+        }
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>, format: GraphFormat) -> io::Result<()> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.render(format))
+    }
+}
+
+/// Stable node id: the entity's bits, which both formats accept unquoted.
+pub fn node_id(e: Entity) -> String {
+    format!("n{}", e.to_bits())
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Where and how often `GraphExportPlugin<V>` writes snapshots.
+#[derive(Resource)]
+pub struct GraphExportConfig<V> {
+    pub dir: PathBuf,
+    /// File stem; snapshots are named `{name}_{elapsed secs}.{ext}`.
+    pub name: String,
+    pub formats: Vec<GraphFormat>,
+    /// Real seconds between periodic exports; `None` exports on demand only.
+    pub interval_secs: Option<f64>,
+    pub attributes: NodeAttributes,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V> GraphExportConfig<V> {
+    pub fn new(dir: impl Into<PathBuf>, name: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            name: name.into(),
+            formats: vec![GraphFormat::GraphMl, GraphFormat::Dot],
+            interval_secs: None,
+            attributes: Arc::new(|_| BTreeMap::new()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Handles `ExportGraph<V>` requests and the periodic export, if any.
+/// Write errors are logged, not fatal.
+pub fn export_graphs<V: EdgeWeight + Send + Sync + 'static>(
+    world: &mut World,
+    mut last: Local<Option<f64>>,
+) {
+    let now = world.resource::<Time<Real>>().elapsed_secs_f64();
+    let requests: Vec<ExportGraph<V>> = world
+        .resource_mut::<Events<ExportGraph<V>>>()
+        .drain()
+        .collect();
+
+    let (dir, name, formats, interval, attributes) = {
+        let cfg = world.resource::<GraphExportConfig<V>>();
+        (
+            cfg.dir.clone(),
+            cfg.name.clone(),
+            cfg.formats.clone(),
+            cfg.interval_secs,
+            cfg.attributes.clone(),
+        )
+    };
+    let mut jobs: Vec<(PathBuf, GraphFormat)> =
+        requests.into_iter().map(|r| (r.path, r.format)).collect();
+    if let Some(every) = interval {
+        let due = last.is_none_or(|t| now - t >= every);
+        if due {
+            *last = Some(now);
+            for &f in &formats {
+                let file = format!("{name}_{:010.0}.{}", now, f.extension());
+                jobs.push((dir.join(file), f));
+            }
+        }
+    }
+    if jobs.is_empty() {
+        return;
+    }
+
+    let snapshot = GraphExport::from_world::<V>(world, |e| attributes(e));
+    for (path, format) in jobs {
+        if let Err(err) = snapshot.write(&path, format) {
+            bevy_log::warn!("graph export to {} failed: {err}", path.display());
+        }
+    }
+}
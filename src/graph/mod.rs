@@ -0,0 +1,13 @@
+pub mod component;
+pub mod events;
+pub mod export;
+pub mod plugin;
+pub mod query;
+pub mod system;
+
+pub use component::{EdgeWeight, Graph};
+pub use events::{EdgeAdded, EdgeRemoved, ExportGraph};
+pub use export::{AttrValue, GraphExport, GraphExportConfig, GraphFormat, NodeAttributes};
+pub use plugin::{GraphExportPlugin, RelationshipPlugin};
+pub use query::Network;
+pub use system::{GraphIndex, RelationshipConfig};
@@ -0,0 +1,95 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::graph::component::EdgeWeight;
+use crate::graph::events::{EdgeAdded, EdgeRemoved, ExportGraph};
+use crate::graph::export::{AttrValue, GraphExportConfig, NodeAttributes, export_graphs};
+use crate::graph::system::{GraphIndex, RelationshipConfig, sync_graph};
+
+/// Maintains `Graph<V>` edges: optional symmetry, despawn cleanup and
+/// `EdgeAdded`/`EdgeRemoved` events. Runs in `PostUpdate`, so edits made
+/// during `Update` are reconciled in the same frame.
+pub struct RelationshipPlugin<V> {
+    undirected: bool,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V> RelationshipPlugin<V> {
+    pub fn directed() -> Self {
+        Self {
+            undirected: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self {
+            undirected: true,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<V: Clone + PartialEq + Send + Sync + 'static> Plugin for RelationshipPlugin<V> {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(RelationshipConfig::<V>::new(self.undirected))
+            .init_resource::<GraphIndex<V>>()
+            .add_event::<EdgeAdded<V>>()
+            .add_event::<EdgeRemoved<V>>()
+            .add_systems(PostUpdate, sync_graph::<V>);
+    }
+}
+
+/// Writes the `Graph<V>` network to GraphML/DOT on `ExportGraph<V>` and,
+/// if `interval_secs` is set, periodically. Runs last in `PostUpdate`, after
+/// `sync_graph`, so snapshots see reconciled edges.
+pub struct GraphExportPlugin<V> {
+    dir: PathBuf,
+    name: String,
+    interval_secs: Option<f64>,
+    attributes: Option<NodeAttributes>,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V> GraphExportPlugin<V> {
+    pub fn new(dir: impl Into<PathBuf>, name: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            name: name.into(),
+            interval_secs: None,
+            attributes: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn every(mut self, secs: f64) -> Self {
+        self.interval_secs = Some(secs);
+        self
+    }
+
+    /// Describe each node; without this nodes are written bare.
+    pub fn attributes(
+        mut self,
+        f: impl Fn(EntityRef) -> BTreeMap<String, AttrValue> + Send + Sync + 'static,
+    ) -> Self {
+        self.attributes = Some(Arc::new(f));
+        self
+    }
+}
+
+impl<V: EdgeWeight + Clone + PartialEq + Send + Sync + 'static> Plugin for GraphExportPlugin<V> {
+    fn build(&self, app: &mut App) {
+        let mut cfg = GraphExportConfig::<V>::new(self.dir.clone(), self.name.clone());
+        cfg.interval_secs = self.interval_secs;
+        if let Some(f) = &self.attributes {
+            cfg.attributes = f.clone();
+        }
+        app.insert_resource(cfg)
+            .add_event::<ExportGraph<V>>()
+            .add_systems(PostUpdate, export_graphs::<V>.after(sync_graph::<V>));
+    }
+}
@@ -0,0 +1,333 @@
+use bevy_ecs::prelude::*;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::graph::component::{EdgeWeight, Graph};
+
+/// Snapshot of every `Graph<V>` in the world as a single network.
+///
+/// Nodes are all graph owners plus every edge target, ordered by `Entity` so
+/// results do not depend on query order. Edge weights come from
+/// `EdgeWeight` and are read as *costs* by `shortest_path`.
+#[derive(Clone, Debug, Default)]
+pub struct Network {
+    nodes: Vec<Entity>,
+    index: HashMap<Entity, usize>,
+    out: Vec<Vec<(usize, f64)>>,
+    // out ∪ in, deduplicated: used where direction does not matter
+    undirected: Vec<Vec<usize>>,
+}
+
+impl Network {
+    pub fn from_world<V: EdgeWeight + Send + Sync + 'static>(world: &mut World) -> Self {
+        let mut q = world.query::<(Entity, &Graph<V>)>();
+        let owners: Vec<Entity> = q.iter(world).map(|(e, _)| e).collect();
+        Self::build(
+            owners,
+            q.iter(world)
+                .flat_map(|(from, g)| g.iter().map(move |(to, v)| (from, to, v.weight()))),
+        )
+    }
+
+    pub fn from_query<V: EdgeWeight + Send + Sync + 'static>(
+        graphs: &Query<(Entity, &Graph<V>)>,
+    ) -> Self {
+        Self::build(
+            graphs.iter().map(|(e, _)| e),
+            graphs
+                .iter()
+                .flat_map(|(from, g)| g.iter().map(move |(to, v)| (from, to, v.weight()))),
+        )
+    }
+
+    pub fn from_edges(edges: impl IntoIterator<Item = (Entity, Entity, f64)>) -> Self {
+        Self::build(std::iter::empty(), edges)
+    }
+
+    // `owners` keeps isolated graph owners in the network.
+    fn build(
+        owners: impl IntoIterator<Item = Entity>,
+        edges: impl IntoIterator<Item = (Entity, Entity, f64)>,
+    ) -> Self {
+        let edges: Vec<_> = edges.into_iter().collect();
+        let mut nodes: Vec<Entity> = owners.into_iter().collect();
+        nodes.extend(edges.iter().flat_map(|&(a, b, _)| [a, b]));
+        nodes.sort();
+        nodes.dedup();
+        let index: HashMap<Entity, usize> = nodes.iter().enumerate().map(|(i, &e)| (e, i)).collect();
+
+        let mut out = vec![Vec::new(); nodes.len()];
+        let mut undirected = vec![Vec::new(); nodes.len()];
+        for (a, b, w) in edges {
+            let (i, j) = (index[&a], index[&b]);
+            out[i].push((j, w));
+            undirected[i].push(j);
+            undirected[j].push(i);
+        }
+        for v in out.iter_mut() {
+            v.sort_by_key(|&(j, _)| j);
+        }
+        for v in undirected.iter_mut() {
+            v.sort();
+            v.dedup();
+        }
+        Self {
+            nodes,
+            index,
+            out,
+            undirected,
+        }
+    }
+
+    pub fn nodes(&self) -> &[Entity] {
+        &self.nodes
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Outgoing neighbours of `e` with their edge weights.
+    pub fn neighbours(&self, e: Entity) -> impl Iterator<Item = (Entity, f64)> + '_ {
+        self.index
+            .get(&e)
+            .into_iter()
+            .flat_map(|&i| self.out[i].iter().map(|&(j, w)| (self.nodes[j], w)))
+    }
+
+    /// Breadth-first visiting order from `start`, following edge direction.
+    pub fn bfs(&self, start: Entity) -> Vec<Entity> {
+        let Some(&s) = self.index.get(&start) else {
+            return Vec::new();
+        };
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from([s]);
+        seen[s] = true;
+        while let Some(i) = queue.pop_front() {
+            order.push(self.nodes[i]);
+            for &(j, _) in &self.out[i] {
+                if !seen[j] {
+                    seen[j] = true;
+                    queue.push_back(j);
+                }
+            }
+        }
+        order
+    }
+
+    /// Depth-first (pre-order) visiting order from `start`, following edge direction.
+    pub fn dfs(&self, start: Entity) -> Vec<Entity> {
+        let Some(&s) = self.index.get(&start) else {
+            return Vec::new();
+        };
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![s];
+        while let Some(i) = stack.pop() {
+            if seen[i] {
+                continue;
+            }
+            seen[i] = true;
+            order.push(self.nodes[i]);
+            // reversed so the lowest neighbour is visited first
+            for &(j, _) in self.out[i].iter().rev() {
+                if !seen[j] {
+                    stack.push(j);
+                }
+            }
+        }
+        order
+    }
+
+    /// Dijkstra over edge weights as costs. Returns total cost and the path,
+    /// endpoints included. Negative weights are treated as zero.
+    pub fn shortest_path(&self, from: Entity, to: Entity) -> Option<(f64, Vec<Entity>)> {
+        let (&s, &t) = (self.index.get(&from)?, self.index.get(&to)?);
+        let mut dist = vec![f64::INFINITY; self.len()];
+        let mut prev = vec![usize::MAX; self.len()];
+        let mut heap = BinaryHeap::new();
+        dist[s] = 0.0;
+        heap.push(Frontier(0.0, s));
+        while let Some(Frontier(d, i)) = heap.pop() {
+            if d > dist[i] {
+                continue;
+            }
+            if i == t {
+                break;
+            }
+            for &(j, w) in &self.out[i] {
+                let nd = d + w.max(0.0);
+                if nd < dist[j] {
+                    dist[j] = nd;
+                    prev[j] = i;
+                    heap.push(Frontier(nd, j));
+                }
+            }
+        }
+        if !dist[t].is_finite() {
+            return None;
+        }
+        let mut path = vec![self.nodes[t]];
+        let mut i = t;
+        while i != s {
+            i = prev[i];
+            path.push(self.nodes[i]);
+        }
+        path.reverse();
+        Some((dist[t], path))
+    }
+
+    /// Weakly connected components, each sorted, largest first.
+    pub fn connected_components(&self) -> Vec<Vec<Entity>> {
+        let mut comp = vec![usize::MAX; self.len()];
+        let mut out: Vec<Vec<Entity>> = Vec::new();
+        for s in 0..self.len() {
+            if comp[s] != usize::MAX {
+                continue;
+            }
+            let c = out.len();
+            let mut members = Vec::new();
+            let mut stack = vec![s];
+            comp[s] = c;
+            while let Some(i) = stack.pop() {
+                members.push(self.nodes[i]);
+                for &j in &self.undirected[i] {
+                    if comp[j] == usize::MAX {
+                        comp[j] = c;
+                        stack.push(j);
+                    }
+                }
+            }
+            members.sort();
+            out.push(members);
+        }
+        out.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+        out
+    }
+
+    /// Distinct neighbours (either direction) divided by `n - 1`.
+    pub fn degree_centrality(&self) -> HashMap<Entity, f64> {
+        let denom = (self.len().max(2) - 1) as f64;
+        self.nodes
+            .iter()
+            .zip(&self.undirected)
+            .map(|(&e, nb)| (e, nb.len() as f64 / denom))
+            .collect()
+    }
+
+    /// Brandes' betweenness over unweighted, directed shortest paths.
+    /// Unnormalised: for each node, the sum over ordered pairs `(s, t)` of the
+    /// fraction of shortest `s -> t` paths that pass through it.
+    pub fn betweenness_centrality(&self) -> HashMap<Entity, f64> {
+        let n = self.len();
+        let mut cb = vec![0.0; n];
+        for s in 0..n {
+            let mut stack = Vec::new();
+            let mut preds: Vec<Vec<usize>> = vec![Vec::new(); n];
+            let mut sigma = vec![0.0; n];
+            let mut dist = vec![-1i64; n];
+            sigma[s] = 1.0;
+            dist[s] = 0;
+            let mut queue = VecDeque::from([s]);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                for &(w, _) in &self.out[v] {
+                    if dist[w] < 0 {
+                        dist[w] = dist[v] + 1;
+                        queue.push_back(w);
+                    }
+                    if dist[w] == dist[v] + 1 {
+                        sigma[w] += sigma[v];
+                        preds[w].push(v);
+                    }
+                }
+            }
+            let mut delta = vec![0.0; n];
+            while let Some(w) = stack.pop() {
+                for &v in &preds[w] {
+                    delta[v] += sigma[v] / sigma[w] * (1.0 + delta[w]);
+                }
+                if w != s {
+                    cb[w] += delta[w];
+                }
+            }
+        }
+        self.nodes.iter().copied().zip(cb).collect()
+    }
+
+    /// Community labels by asynchronous label propagation (edges treated as
+    /// undirected, ties broken at random). Labels are renumbered from 0 in
+    /// node order.
+    pub fn label_propagation<R: Rng + ?Sized>(
+        &self,
+        max_iterations: usize,
+        rng: &mut R,
+    ) -> HashMap<Entity, usize> {
+        let n = self.len();
+        let mut labels: Vec<usize> = (0..n).collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        for _ in 0..max_iterations {
+            order.shuffle(rng);
+            let mut changed = false;
+            for &i in &order {
+                if self.undirected[i].is_empty() {
+                    continue;
+                }
+                let mut counts: HashMap<usize, usize> = HashMap::new();
+                for &j in &self.undirected[i] {
+                    *counts.entry(labels[j]).or_default() += 1;
+                }
+                let best = counts.values().copied().max().unwrap_or(0);
+                if counts.get(&labels[i]) == Some(&best) {
+                    continue; // already holds a majority label
+                }
+                let mut top: Vec<usize> = counts
+                    .into_iter()
+                    .filter(|&(_, c)| c == best)
+                    .map(|(l, _)| l)
+                    .collect();
+                top.sort();
+                labels[i] = top[rng.random_range(0..top.len())];
+                changed = true;
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut renumber: HashMap<usize, usize> = HashMap::new();
+        self.nodes
+            .iter()
+            .zip(labels)
+            .map(|(&e, l)| {
+                let next = renumber.len();
+                (e, *renumber.entry(l).or_insert(next))
+            })
+            .collect()
+    }
+}
+
+// Min-heap entry for Dijkstra.
+#[derive(PartialEq)]
+struct Frontier(f64, usize);
+
+impl Eq for Frontier {}
+
+impl Ord for Frontier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.total_cmp(&self.0).then(self.1.cmp(&other.1))
+    }
+}
+
+impl PartialOrd for Frontier {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,233 @@
+use bevy_ecs::entity::Entities;
+use bevy_ecs::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+
+use crate::graph::component::Graph;
+use crate::graph::events::{EdgeAdded, EdgeRemoved};
+
+#[derive(Resource)]
+pub struct RelationshipConfig<V> {
+    /// Mirror every edge change onto the other endpoint.
+    pub undirected: bool,
+    _marker: PhantomData<fn() -> V>,
+}
+
+impl<V> RelationshipConfig<V> {
+    pub fn new(undirected: bool) -> Self {
+        Self {
+            undirected,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Last edge set seen by `sync_graph`, used to diff changed graphs and to
+/// find the sources pointing at a despawned entity.
+#[derive(Resource)]
+pub struct GraphIndex<V> {
+    edges: HashMap<Entity, HashMap<Entity, V>>,
+    incoming: HashMap<Entity, HashSet<Entity>>,
+}
+
+impl<V> Default for GraphIndex<V> {
+    fn default() -> Self {
+        Self {
+            edges: HashMap::new(),
+            incoming: HashMap::new(),
+        }
+    }
+}
+
+impl<V> GraphIndex<V> {
+    fn insert(&mut self, from: Entity, to: Entity, value: V) {
+        self.edges.entry(from).or_default().insert(to, value);
+        self.incoming.entry(to).or_default().insert(from);
+    }
+
+    fn remove(&mut self, from: Entity, to: Entity) -> Option<V> {
+        let v = self.edges.get_mut(&from)?.remove(&to)?;
+        if let Some(src) = self.incoming.get_mut(&to) {
+            src.remove(&from);
+            if src.is_empty() {
+                self.incoming.remove(&to);
+            }
+        }
+        Some(v)
+    }
+
+    /// Entities with an edge pointing at `to`.
+    pub fn sources(&self, to: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.incoming.get(&to).into_iter().flatten().copied()
+    }
+}
+
+/// Keeps `Graph<V>` consistent: diffs changed graphs against the index,
+/// mirrors changes to the other endpoint in undirected mode, drops edges
+/// touching despawned entities, and reports every added/removed edge.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_graph<V: Clone + PartialEq + Send + Sync + 'static>(
+    mut commands: Commands,
+    cfg: Res<RelationshipConfig<V>>,
+    mut index: ResMut<GraphIndex<V>>,
+    mut graphs: Query<(Entity, Mut<Graph<V>>)>,
+    mut dropped: RemovedComponents<Graph<V>>,
+    entities: &Entities,
+    mut added_w: EventWriter<EdgeAdded<V>>,
+    mut removed_w: EventWriter<EdgeRemoved<V>>,
+) {
+    let mut removed: Vec<EdgeRemoved<V>> = Vec::new();
+
+    // Graph component gone (usually despawned): its outgoing edges go with it.
+    let mut gone: Vec<Entity> = dropped.read().collect();
+    gone.sort();
+    gone.dedup();
+    for from in gone {
+        if graphs.contains(from) {
+            continue; // removed and re-added in the same frame
+        }
+        let targets: Vec<Entity> = index
+            .edges
+            .get(&from)
+            .map(|m| m.keys().copied().collect())
+            .unwrap_or_default();
+        for to in targets {
+            if let Some(value) = index.remove(from, to) {
+                removed.push(EdgeRemoved { from, to, value });
+            }
+        }
+        index.edges.remove(&from);
+    }
+
+    // Edges pointing at despawned entities.
+    let mut dead: Vec<Entity> = index
+        .incoming
+        .keys()
+        .copied()
+        .filter(|&e| !entities.contains(e))
+        .collect();
+    dead.sort();
+    for to in dead {
+        let sources: Vec<Entity> = index.sources(to).collect();
+        for from in sources {
+            if let Ok((_, mut g)) = graphs.get_mut(from) {
+                g.remove(&to);
+            }
+            if let Some(value) = index.remove(from, to) {
+                removed.push(EdgeRemoved { from, to, value });
+            }
+        }
+    }
+
+    // In undirected mode the edges dropped above leave a dangling mirror.
+    if cfg.undirected {
+        let mirrors: Vec<(Entity, Entity)> = removed.iter().map(|r| (r.to, r.from)).collect();
+        for (from, to) in mirrors {
+            if let Ok((_, mut g)) = graphs.get_mut(from) {
+                g.remove(&to);
+            }
+            if let Some(value) = index.remove(from, to) {
+                removed.push(EdgeRemoved { from, to, value });
+            }
+        }
+    }
+
+    // Diff graphs changed since the last run.
+    let mut changed: Vec<Entity> = graphs
+        .iter_mut()
+        .filter(|(_, g)| g.is_changed())
+        .map(|(e, _)| e)
+        .collect();
+    changed.sort();
+
+    let mut added: Vec<EdgeAdded<V>> = Vec::new();
+    let mut spawned: HashMap<Entity, Graph<V>> = HashMap::new();
+    for from in changed {
+        let Ok((_, g)) = graphs.get(from) else {
+            continue;
+        };
+        let current: Vec<(Entity, V)> = g.iter().map(|(to, v)| (to, v.clone())).collect();
+        let previous = index.edges.get(&from).cloned().unwrap_or_default();
+
+        let mut upserts: Vec<(Entity, V)> = Vec::new();
+        for (to, value) in current.iter() {
+            match previous.get(to) {
+                Some(old) if old == value => {}
+                Some(_) => upserts.push((*to, value.clone())),
+                None => {
+                    upserts.push((*to, value.clone()));
+                    added.push(EdgeAdded {
+                        from,
+                        to: *to,
+                        value: value.clone(),
+                    });
+                }
+            }
+        }
+        let drops: Vec<Entity> = previous
+            .keys()
+            .copied()
+            .filter(|to| !current.iter().any(|(e, _)| e == to))
+            .collect();
+
+        for (to, value) in upserts {
+            index.insert(from, to, value.clone());
+            if !cfg.undirected || to == from || !entities.contains(to) {
+                continue;
+            }
+            let mirrored = match graphs.get_mut(to) {
+                Ok((_, mut tg)) => {
+                    let fresh = !tg.contains(&from);
+                    if tg.get(&from) != Some(&value) {
+                        tg.set(from, value.clone());
+                    }
+                    fresh
+                }
+                Err(_) => {
+                    // Target has no Graph yet: collect and insert once below.
+                    let tg = spawned.entry(to).or_default();
+                    let fresh = !tg.contains(&from);
+                    tg.set(from, value.clone());
+                    fresh
+                }
+            };
+            let was_indexed = index.edges.get(&to).is_some_and(|m| m.contains_key(&from));
+            index.insert(to, from, value.clone());
+            if mirrored && !was_indexed {
+                added.push(EdgeAdded {
+                    from: to,
+                    to: from,
+                    value,
+                });
+            }
+        }
+
+        for to in drops {
+            if let Some(value) = index.remove(from, to) {
+                removed.push(EdgeRemoved { from, to, value });
+            }
+            if !cfg.undirected {
+                continue;
+            }
+            if let Ok((_, mut tg)) = graphs.get_mut(to) {
+                tg.remove(&from);
+            }
+            if let Some(g) = spawned.get_mut(&to) {
+                g.remove(&from);
+            }
+            if let Some(value) = index.remove(to, from) {
+                removed.push(EdgeRemoved {
+                    from: to,
+                    to: from,
+                    value,
+                });
+            }
+        }
+    }
+
+    for (e, g) in spawned {
+        commands.entity(e).insert(g);
+    }
+    removed_w.write_batch(removed);
+    added_w.write_batch(added);
+}
@@ -20,11 +20,18 @@ pub struct Resume {
     pub applicant: Entity,
     pub job: Entity,
     pub role_index: usize,
+    pub referred_by: Option<Entity>,
 }
 
 // Hiring behavior knobs (kept minimal)
 #[derive(Resource)]
 pub struct HiringConfig {
     pub max_hires_per_role_per_cycle: u32,
+    /// Added to a referred applicant's ranking score (fit in `[0, 1]`, or -1
+    /// when the role has no profile).
+    pub referral_bonus: f64,
+    /// Weight of the applicant's level in the role's trained skills (`[0, 1]`)
+    /// in the ranking score.
+    pub skill_weight: f64,
 }
 
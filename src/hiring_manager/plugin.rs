@@ -1,37 +1,85 @@
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::{Real, Time};
+use std::collections::{HashMap, HashSet};
 
+use crate::economy::{Account, Payer};
+use crate::graph::Graph;
 use crate::gregslist::component::{Advert, Gregslist, VacancyDirty};
-use crate::hiring_manager::component::{Age, ApplicationInbox, HiringConfig, Resume, Unemployed};
-use crate::jobs::component::{Constraint, Job};
+use crate::hiring_manager::component::{ApplicationInbox, HiringConfig, Resume, Unemployed};
+use crate::jobs::component::{vacancy, Employment, Funds, Job};
+use crate::jobs::events::Promoted;
+use crate::person::{AgingConfig, Skills};
+use crate::personality::Personality;
+use crate::social::Tie;
 
 pub struct HiringManagerPlugin {
     max_hires_per_role_per_cycle: u32,
+    referral_bonus: f64,
+    skill_weight: f64,
 }
 
 impl HiringManagerPlugin {
     pub fn new(max_hires_per_role_per_cycle: u32) -> Self {
         Self {
             max_hires_per_role_per_cycle,
+            referral_bonus: 0.25,
+            skill_weight: 0.5,
         }
     }
+
+    /// Score bonus for applicants referred by a member of the job; 0 disables referrals' priority.
+    pub fn with_referral_bonus(mut self, bonus: f64) -> Self {
+        self.referral_bonus = bonus;
+        self
+    }
+
+    /// Score weight of applicants' skill in what the role trains; 0 ignores skills.
+    pub fn with_skill_weight(mut self, weight: f64) -> Self {
+        self.skill_weight = weight;
+        self
+    }
 }
 
 impl Plugin for HiringManagerPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(HiringConfig {
             max_hires_per_role_per_cycle: self.max_hires_per_role_per_cycle,
+            referral_bonus: self.referral_bonus,
+            skill_weight: self.skill_weight,
         })
         .init_resource::<ApplicationInbox>()
+        .init_resource::<AgingConfig>()
+        .add_event::<Promoted>()
         .add_systems(Startup, mark_jobs_dirty_on_startup)
         .add_systems(
             Update,
-            (post_job_openings, apply_for_jobs, evaluate_and_assign).chain(),
+            (
+                promote_from_within,
+                post_job_openings,
+                apply_for_jobs,
+                refer_contacts,
+                evaluate_and_assign,
+            )
+                .chain(),
         );
     }
 }
 
+// What `job` can spend on new seats, when it or its payer keeps an account.
+fn funds(
+    job_entity: Entity,
+    job: &Job,
+    accounts: &Query<&Account>,
+    payers: &Query<&Payer>,
+) -> Option<Funds> {
+    let holder = payers.get(job_entity).map_or(job_entity, |p| p.0);
+    accounts.get(holder).ok().map(|a| Funds {
+        balance: a.balance,
+        payroll: job.payroll(),
+    })
+}
+
 // Seed initial postings
 fn mark_jobs_dirty_on_startup(
     jobs: Query<Entity, With<Job>>,
@@ -42,20 +90,104 @@ fn mark_jobs_dirty_on_startup(
     }
 }
 
-// Post/remove adverts so Gregslist reflects current vacancies for dirty jobs.
+// Best internal candidate for the first role below `min`: a member of a role
+// that promotes into it who meets its constraints, ranked like applicants.
+fn next_promotion(
+    job: &Job,
+    people: &Query<EntityRef, Without<Job>>,
+    cfg: &HiringConfig,
+) -> Option<(Entity, usize, usize, Option<f64>)> {
+    for (to, (spec, members)) in job.roles.iter().enumerate() {
+        if members.len() as u32 >= spec.min {
+            continue;
+        }
+        let mut best: Option<(f64, Entity, usize, Option<f64>)> = None;
+        for from in job.feeders(to) {
+            for &m in &job.roles[from].1 {
+                let Ok(person) = people.get(m) else {
+                    continue;
+                };
+                if spec.admits(&person).is_err() {
+                    continue;
+                }
+                let fit = spec.fit(person.get::<Personality>());
+                let score = fit.unwrap_or(-1.0)
+                    + cfg.skill_weight * spec.skill_match(person.get::<Skills>());
+                if best.is_none_or(|(s, ..)| score > s) {
+                    best = Some((score, m, from, fit));
+                }
+            }
+        }
+        if let Some((_, m, from, fit)) = best {
+            return Some((m, from, to, fit));
+        }
+    }
+    None
+}
+
+// Fill vacancies from feeder roles before they are advertised. Each promotion
+// opens a seat below, which may be filled from further down in turn; what
+// stays open is posted to the Gregslist as usual.
+fn promote_from_within(
+    mut commands: Commands,
+    cfg: Res<HiringConfig>,
+    mut jobs: Query<(Entity, &mut Job)>,
+    people: Query<EntityRef, Without<Job>>,
+    mut promoted: EventWriter<Promoted>,
+    mut dirty: EventWriter<VacancyDirty>,
+) {
+    for (job_entity, mut job) in jobs.iter_mut() {
+        let mut moved = false;
+        // bounded, in case a hand-built promotion path loops
+        for _ in 0..job.headcount() {
+            let Some((person, from, to, fit)) = next_promotion(&job, &people, &cfg) else {
+                break;
+            };
+            job.roles[from].1.retain(|&m| m != person);
+            job.roles[to].1.push(person);
+            let Ok(entity) = people.get(person) else {
+                continue;
+            };
+            let mut ec = commands.entity(person);
+            if let Some(&emp) = entity.get::<Employment>() {
+                ec.insert(Employment {
+                    role_index: to,
+                    fit,
+                    ..emp
+                });
+            }
+            promoted.write(Promoted {
+                person,
+                job: job_entity,
+                from,
+                to,
+            });
+            moved = true;
+        }
+        if moved {
+            dirty.write(VacancyDirty { job: job_entity });
+        }
+    }
+}
+
+// Post/remove adverts so Gregslist reflects current vacancies for dirty jobs:
+// required seats, plus the optional ones each role's policy allows.
 fn post_job_openings(
     time: Res<Time<Real>>,
     mut board: ResMut<Gregslist>,
     mut dirty_events: EventReader<VacancyDirty>,
     jobs: Query<&Job>,
+    accounts: Query<&Account>,
+    payers: Query<&Payer>,
 ) {
     let now = time.elapsed_secs();
 
     for ev in dirty_events.read() {
         if let Ok(job_data) = jobs.get(ev.job) {
+            let funds = funds(ev.job, job_data, &accounts, &payers);
             // Reconcile each role of this job.
-            for (i, (spec, members)) in job_data.roles.iter().enumerate() {
-                let needed = spec.min.saturating_sub(members.len() as u32);
+            for (i, role) in job_data.roles.iter().enumerate() {
+                let needed = vacancy(role, funds).total();
                 let key = (ev.job, i);
 
                 if needed > 0 {
@@ -85,38 +217,111 @@ fn post_job_openings(
 fn apply_for_jobs(
     board: Res<Gregslist>,
     jobs: Query<&Job>,
-    ages: Query<&Age>,
+    people: Query<EntityRef>,
     applicants: Query<Entity, With<Unemployed>>,
     mut inbox: ResMut<ApplicationInbox>,
 ) {
     // v1: naive flood — each unemployed applies to all matching adverts (inbox is drained next system)
     for applicant in applicants.iter() {
-        let age = ages.get(applicant).ok().map(|a| a.years);
+        let Ok(person) = people.get(applicant) else {
+            continue;
+        };
         for ad in board.ads.iter() {
-            if let Ok(job) = jobs.get(ad.job) {
-                if let Some((spec, members)) = job.roles.get(ad.role_index) {
-                    // skip if already a member
-                    if members.iter().any(|&e| e == applicant) {
-                        continue;
-                    }
-                    // simple constraints (age)
-                    if constraints_ok(spec, age) {
-                        inbox.resumes.push(Resume {
-                            applicant,
-                            job: ad.job,
-                            role_index: ad.role_index,
-                        });
-                    }
+            if let Ok(job) = jobs.get(ad.job)
+                && let Some((spec, members)) = job.roles.get(ad.role_index)
+            {
+                // skip if already a member
+                if members.contains(&applicant) {
+                    continue;
+                }
+                if spec.admits(&person).is_ok() {
+                    inbox.resumes.push(Resume {
+                        applicant,
+                        job: ad.job,
+                        role_index: ad.role_index,
+                        referred_by: None,
+                    });
                 }
             }
         }
     }
 }
 
-// Hire up to available capacity and configured batch size; remove Unemployed on success.
+// Members of a job tell their unemployed contacts (Graph<Tie> neighbours) about
+// its open adverts. The strongest tie is credited; contacts who have not
+// applied through the board apply now.
+fn refer_contacts(
+    board: Res<Gregslist>,
+    jobs: Query<&Job>,
+    people: Query<EntityRef>,
+    contacts: Query<&Graph<Tie>>,
+    unemployed: Query<(), With<Unemployed>>,
+    mut inbox: ResMut<ApplicationInbox>,
+) {
+    // (applicant, job, role_index) -> (tie strength, referrer)
+    let mut best: HashMap<(Entity, Entity, usize), (f32, Entity)> = HashMap::new();
+    for ad in board.ads.iter() {
+        let Ok(job) = jobs.get(ad.job) else {
+            continue;
+        };
+        let Some((spec, members)) = job.roles.get(ad.role_index) else {
+            continue;
+        };
+        for &referrer in job.roles.iter().flat_map(|(_, staff)| staff) {
+            let Ok(graph) = contacts.get(referrer) else {
+                continue;
+            };
+            for (friend, tie) in graph.iter() {
+                if !unemployed.contains(friend)
+                    || members.contains(&friend)
+                    || !people.get(friend).is_ok_and(|p| spec.admits(&p).is_ok())
+                {
+                    continue;
+                }
+                let candidate = (tie.strength, referrer);
+                best.entry((friend, ad.job, ad.role_index))
+                    .and_modify(|b| {
+                        if candidate.0 > b.0 || (candidate.0 == b.0 && candidate.1 < b.1) {
+                            *b = candidate;
+                        }
+                    })
+                    .or_insert(candidate);
+            }
+        }
+    }
+    if best.is_empty() {
+        return;
+    }
+
+    for r in inbox.resumes.iter_mut() {
+        if let Some((_, by)) = best.remove(&(r.applicant, r.job, r.role_index)) {
+            r.referred_by = Some(by);
+        }
+    }
+    let mut rest: Vec<_> = best.into_iter().collect();
+    rest.sort_by_key(|&(key, _)| key);
+    for ((applicant, job, role_index), (_, by)) in rest {
+        inbox.resumes.push(Resume {
+            applicant,
+            job,
+            role_index,
+            referred_by: Some(by),
+        });
+    }
+}
+
+// Hire the best-fitting applicants up to the role's vacancies and configured batch size;
+// remove Unemployed and record the Employment on success.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn evaluate_and_assign(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
     mut inbox: ResMut<ApplicationInbox>,
     mut jobs: Query<&mut Job>,
+    accounts: Query<&Account>,
+    payers: Query<&Payer>,
+    traits: Query<&Personality>,
+    skills: Query<&Skills>,
     mut commands: Commands,
     cfg: Res<HiringConfig>,
     mut dirty: EventWriter<VacancyDirty>,
@@ -125,71 +330,80 @@ fn evaluate_and_assign(
         return;
     }
 
-    // Group applications by (job, role_index) to count capacity once
-    // (simple single-pass approach: we’ll compute on demand per resume)
-    let resumes = std::mem::take(&mut inbox.resumes);
-
-    for r in resumes {
-        if let Ok(mut job) = jobs.get_mut(r.job) {
-            if let Some((spec, members)) = job.roles.get_mut(r.role_index) {
-                // capacity left = min(max - current, batch, min - current)
-                let current = members.len() as u32;
-                let cap_max = spec.max.saturating_sub(current);
-                let cap_min = spec.min.saturating_sub(current);
-                let batch_cap = cfg.max_hires_per_role_per_cycle;
-
-                // If already at or above max, skip
-                if cap_max == 0 {
-                    continue;
-                }
-
-                // Hire now if we still need seats (prefer filling to min first).
-                // We admit within batch cap by checking how many we’ve added this frame.
-                // Simple guard: only push if still below both min/max bound.
-                let still_below_min = cap_min > 0;
-                let still_below_max = cap_max > 0;
-
-                if still_below_min || still_below_max {
-                    // Prevent duplicate membership
-                    if !members.iter().any(|&e| e == r.applicant) {
-                        // Count how many we already added this frame to this role
-                        // (approx by checking members length delta after each push)
-                        if members.len() as u32 - current < batch_cap {
-                            members.push(r.applicant);
-                            commands.entity(r.applicant).remove::<Unemployed>();
-                            dirty.write(VacancyDirty { job: r.job });
-                        }
-                    }
-                }
-            }
-        }
+    // Group applications by (job, role_index), keeping inbox order within each
+    // group so equally fit applicants are still served first-come.
+    let mut order: Vec<(Entity, usize)> = Vec::new();
+    let mut groups: HashMap<(Entity, usize), Vec<(Entity, Option<Entity>)>> = HashMap::new();
+    for r in std::mem::take(&mut inbox.resumes) {
+        let key = (r.job, r.role_index);
+        groups
+            .entry(key)
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push((r.applicant, r.referred_by));
     }
-}
 
-// --- helpers ---
-fn constraints_ok(spec: &crate::jobs::component::RoleSpec, maybe_age: Option<u8>) -> bool {
-    use Constraint::*;
-    for c in &spec.constraints {
-        match *c {
-            AgeLessThan(n) => {
-                if let Some(age) = maybe_age {
-                    if !(age < n) {
-                        return false;
-                    }
+    let mut hired: HashSet<Entity> = HashSet::new();
+    for key @ (job_entity, role_index) in order {
+        let Ok(mut job) = jobs.get_mut(job_entity) else {
+            continue;
+        };
+        let funds = funds(job_entity, &job, &accounts, &payers);
+        let Some(role) = job.roles.get_mut(role_index) else {
+            continue;
+        };
+        let open = vacancy(role, funds)
+            .total()
+            .min(cfg.max_hires_per_role_per_cycle);
+        let (spec, members) = role;
+
+        let mut ranked: Vec<(Entity, Option<f64>, Option<Entity>, f64)> = groups
+            .remove(&key)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(a, by)| {
+                let skill = spec.skill_match(skills.get(a).ok());
+                (a, spec.fit(traits.get(a).ok()), by, skill)
+            })
+            .collect();
+        // best fit first, referrals and skill boosted; unscored applicants go last (sort is stable)
+        let score = |&(_, fit, by, skill): &(Entity, Option<f64>, Option<Entity>, f64)| {
+            fit.unwrap_or(-1.0)
+                + if by.is_some() {
+                    cfg.referral_bonus
                 } else {
-                    return false;
+                    0.0
                 }
+                + cfg.skill_weight * skill
+        };
+        ranked.sort_by(|a, b| score(b).total_cmp(&score(a)));
+
+        let mut taken = 0;
+        for (applicant, fit, referred_by, _) in ranked {
+            if taken >= open {
+                break;
             }
-            AgeAtLeast(n) => {
-                if let Some(age) = maybe_age {
-                    if !(age >= n) {
-                        return false;
-                    }
-                } else {
-                    return false;
-                }
+            // Prevent duplicate membership, and hiring one person into two roles at once
+            if members.contains(&applicant) || !hired.insert(applicant) {
+                continue;
             }
+            members.push(applicant);
+            commands
+                .entity(applicant)
+                .remove::<Unemployed>()
+                .insert(Employment {
+                    job: job_entity,
+                    role_index,
+                    since: aging.years(time.elapsed_secs_f64()),
+                    fit,
+                    referred_by,
+                });
+            taken += 1;
+        }
+        if taken > 0 {
+            dirty.write(VacancyDirty { job: job_entity });
         }
     }
-    true
 }
@@ -7,22 +7,28 @@ pub struct Inventory {
     pub items: Vec<Entity>,
 }
 
+/// A good that can be held in an `Inventory`, e.g. `"bread"`.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub kind: String,
+}
+
 /// Add `item` to `container` if it's not already present.
 pub fn inv_add(mut q_inv: Query<&mut Inventory>, container: Entity, item: Entity) {
-    if let Ok(mut inv) = q_inv.get_mut(container) {
-        if !inv.items.iter().any(|&e| e == item) {
-            inv.items.push(item);
-        }
+    if let Ok(mut inv) = q_inv.get_mut(container)
+        && !inv.items.contains(&item)
+    {
+        inv.items.push(item);
     }
 }
 
 /// Remove `item` from `container`. Returns true if removed.
 pub fn inv_remove(mut q_inv: Query<&mut Inventory>, container: Entity, item: Entity) -> bool {
-    if let Ok(mut inv) = q_inv.get_mut(container) {
-        if let Some(i) = inv.items.iter().position(|&e| e == item) {
-            inv.items.swap_remove(i);
-            return true;
-        }
+    if let Ok(mut inv) = q_inv.get_mut(container)
+        && let Some(i) = inv.items.iter().position(|&e| e == item)
+    {
+        inv.items.swap_remove(i);
+        return true;
     }
     false
 }
@@ -30,18 +36,17 @@ pub fn inv_remove(mut q_inv: Query<&mut Inventory>, container: Entity, item: Ent
 /// Move `item` from `from` (if provided) to `to`. Skips duplicate adds.
 pub fn inv_move(mut q_inv: Query<&mut Inventory>, from: Option<Entity>, to: Entity, item: Entity) {
     // remove from source if given
-    if let Some(src) = from {
-        if let Ok(mut inv) = q_inv.get_mut(src) {
-            if let Some(i) = inv.items.iter().position(|&e| e == item) {
-                inv.items.swap_remove(i);
-            }
-        }
+    if let Some(src) = from
+        && let Ok(mut inv) = q_inv.get_mut(src)
+        && let Some(i) = inv.items.iter().position(|&e| e == item)
+    {
+        inv.items.swap_remove(i);
     }
     // add to destination if not already there
-    if let Ok(mut inv) = q_inv.get_mut(to) {
-        if !inv.items.iter().any(|&e| e == item) {
-            inv.items.push(item);
-        }
+    if let Ok(mut inv) = q_inv.get_mut(to)
+        && !inv.items.contains(&item)
+    {
+        inv.items.push(item);
     }
 }
 
@@ -49,7 +54,7 @@ pub fn inv_move(mut q_inv: Query<&mut Inventory>, from: Option<Entity>, to: Enti
 pub fn inv_contains(q_inv: &Query<&Inventory>, container: Entity, item: Entity) -> bool {
     q_inv
         .get(container)
-        .map(|inv| inv.items.iter().any(|&e| e == item))
+        .map(|inv| inv.items.contains(&item))
         .unwrap_or(false)
 }
 
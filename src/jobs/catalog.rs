@@ -0,0 +1,526 @@
+use bevy_ecs::prelude::*;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::hiring_manager::component::Unemployed;
+use crate::jobs::component::{Job, Recipe, RoleSpec, TraitProfile, VacancyPolicy};
+use crate::jobs::constraint::{ComponentCheck, Constraint};
+use crate::person::{Education, Person, Position, Sex, Skills};
+use crate::personality::{Personality, Trait};
+
+/// One job type as written in a `.ron` file. A file holds a list of these:
+///
+/// ```ron
+/// [
+///     (
+///         name: "school",
+///         roles: [
+///             (name: "student", min: 20, max: 200, constraints: [AgeLessThan(18)]),
+///             (name: "teacher", min: 1, max: 10, wage: 30000.0,
+///              constraints: [AgeAtLeast(18)], prefers: [(Conscientiousness, 0.8, 1.0)]),
+///         ],
+///     ),
+/// ]
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobDef {
+    pub name: String,
+    pub roles: Vec<RoleDef>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RoleDef {
+    /// Used in error messages, by `reports_to` / `promotes_to`, and to
+    /// match live roles to their new definition on reload.
+    #[serde(default)]
+    pub name: String,
+    pub min: u32,
+    pub max: u32,
+    #[serde(default)]
+    pub wage: f32,
+    #[serde(default)]
+    pub constraints: Vec<ConstraintDef>,
+    /// `(trait, target, weight)`, as in `JobBuilder::prefers`.
+    #[serde(default)]
+    pub prefers: Vec<(Trait, f64, f64)>,
+    /// Skills members practise on the job.
+    #[serde(default)]
+    pub trains: Vec<String>,
+    /// Name of the supervising role in the same job.
+    #[serde(default)]
+    pub reports_to: String,
+    /// Name of the role members are promoted into.
+    #[serde(default)]
+    pub promotes_to: String,
+    /// Years of education gained per year in the role.
+    #[serde(default)]
+    pub schooling: f32,
+    /// Enrol everyone the role admits.
+    #[serde(default)]
+    pub compulsory: bool,
+    /// How many seats above `min` to advertise.
+    #[serde(default)]
+    pub vacancy_policy: VacancyPolicy,
+    /// What members make, e.g. `[(output: "bread", rate: 500.0, inputs: [("flour", 1)])]`.
+    #[serde(default)]
+    pub produces: Vec<Recipe>,
+}
+
+/// File form of `Constraint`; `Has` names a registered component.
+#[derive(Clone, Debug, Deserialize)]
+pub enum ConstraintDef {
+    AgeLessThan(u8),
+    AgeAtLeast(u8),
+    AgeBetween(u8, u8),
+    TraitAtLeast(Trait, f64),
+    TraitAtMost(Trait, f64),
+    MinEducation(f32),
+    MinSkill(String, f32),
+    Sex(Sex),
+    LivesWithin { x: f32, y: f32, radius: f32 },
+    Has(String),
+    And(Vec<ConstraintDef>),
+    Or(Vec<ConstraintDef>),
+    Not(Box<ConstraintDef>),
+}
+
+#[derive(Debug)]
+pub enum CatalogError {
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    Parse {
+        origin: String,
+        line: usize,
+        col: usize,
+        message: String,
+    },
+    /// Every problem found in the file, one per line.
+    Invalid {
+        origin: String,
+        problems: Vec<String>,
+    },
+}
+
+impl fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogError::Io { path, error } => write!(f, "{}: {error}", path.display()),
+            CatalogError::Parse {
+                origin,
+                line,
+                col,
+                message,
+            } => write!(f, "{origin}:{line}:{col}: {message}"),
+            CatalogError::Invalid { origin, problems } => {
+                write!(f, "{origin}: invalid job definitions")?;
+                for p in problems {
+                    write!(f, "\n  {p}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {}
+
+/// A validated job type, ready to instantiate.
+#[derive(Clone, Debug)]
+pub struct JobTemplate {
+    pub name: String,
+    /// File (or other source) the definition came from.
+    pub origin: String,
+    pub roles: Vec<RoleSpec>,
+}
+
+impl JobTemplate {
+    /// A fresh `Job` with these roles and no members.
+    pub fn instantiate(&self) -> Job {
+        Job {
+            roles: self.roles.iter().map(|r| (r.clone(), Vec::new())).collect(),
+        }
+    }
+}
+
+/// Job types loaded from files, by name.
+#[derive(Resource)]
+pub struct JobCatalog {
+    templates: BTreeMap<String, JobTemplate>,
+    /// Names `Has(..)` may refer to.
+    components: HashMap<String, ComponentCheck>,
+    /// Loaded files with their modification time, for hot reload.
+    files: BTreeMap<PathBuf, Option<SystemTime>>,
+    /// Directories scanned by `load_dir`; new files there are picked up on reload.
+    dirs: Vec<PathBuf>,
+}
+
+impl Default for JobCatalog {
+    fn default() -> Self {
+        let mut catalog = Self {
+            templates: BTreeMap::new(),
+            components: HashMap::new(),
+            files: BTreeMap::new(),
+            dirs: Vec::new(),
+        };
+        catalog
+            .register::<Person>()
+            .register::<Personality>()
+            .register::<Unemployed>()
+            .register::<Education>()
+            .register::<Skills>()
+            .register::<Position>()
+            .register::<Sex>();
+        catalog
+    }
+}
+
+// Following `promotes_to` from some role comes back to a role already visited.
+fn promotion_cycle(roles: &[RoleSpec]) -> bool {
+    (0..roles.len()).any(|start| {
+        let mut at = start;
+        for _ in 0..roles.len() {
+            match roles.get(at).and_then(|r| r.promotes_to) {
+                Some(next) => at = next,
+                None => return false,
+            }
+        }
+        true
+    })
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// `.ron` files directly inside `dir`, sorted by name.
+pub fn ron_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.extension().is_some_and(|x| x == "ron"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+impl JobCatalog {
+    /// Let `Has("<type name>")` refer to component `T`.
+    pub fn register<T: Component>(&mut self) -> &mut Self {
+        if let Constraint::Has(check) = Constraint::has::<T>() {
+            self.components.insert(check.name.to_string(), check);
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&JobTemplate> {
+        self.templates.get(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+
+    /// A fresh `Job` of type `name`, if it is in the catalog.
+    pub fn instantiate(&self, name: &str) -> Option<Job> {
+        self.get(name).map(JobTemplate::instantiate)
+    }
+
+    /// Parse and validate the job types in `src`, replacing whatever
+    /// `origin` defined before. Nothing changes if any of it is invalid.
+    /// Returns the names it defines.
+    pub fn load_str(&mut self, origin: &str, src: &str) -> Result<Vec<String>, CatalogError> {
+        let defs: Vec<JobDef> = ron::de::from_str(src).map_err(|e| CatalogError::Parse {
+            origin: origin.to_string(),
+            line: e.position.line,
+            col: e.position.col,
+            message: e.code.to_string(),
+        })?;
+
+        let mut problems = Vec::new();
+        let mut loaded: Vec<JobTemplate> = Vec::new();
+        for def in &defs {
+            if def.name.trim().is_empty() {
+                problems.push("job with an empty name".to_string());
+                continue;
+            }
+            if loaded.iter().any(|t| t.name == def.name) {
+                problems.push(format!("{}: defined twice", def.name));
+            }
+            if let Some(other) = self.templates.get(&def.name)
+                && other.origin != origin
+            {
+                problems.push(format!("{}: already defined in {}", def.name, other.origin));
+            }
+            match self.template(def, origin) {
+                Ok(t) => loaded.push(t),
+                Err(mut p) => problems.append(&mut p),
+            }
+        }
+        if !problems.is_empty() {
+            return Err(CatalogError::Invalid {
+                origin: origin.to_string(),
+                problems,
+            });
+        }
+
+        self.templates.retain(|_, t| t.origin != origin);
+        let names = loaded.iter().map(|t| t.name.clone()).collect();
+        for t in loaded {
+            self.templates.insert(t.name.clone(), t);
+        }
+        Ok(names)
+    }
+
+    /// Load one file and remember it for hot reload.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<Vec<String>, CatalogError> {
+        let path = path.as_ref();
+        let stamp = modified(path);
+        let src = std::fs::read_to_string(path).map_err(|error| CatalogError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        // remembered even when invalid, so fixing the file triggers a reload
+        self.files.insert(path.to_path_buf(), stamp);
+        self.load_str(&path.display().to_string(), &src)
+    }
+
+    /// Load every `.ron` file in `dir`. Files that fail are reported and skipped.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Vec<CatalogError> {
+        let dir = dir.as_ref();
+        if !self.dirs.iter().any(|d| d == dir) {
+            self.dirs.push(dir.to_path_buf());
+        }
+        match ron_files(dir) {
+            Ok(files) => files
+                .into_iter()
+                .filter_map(|f| self.load_file(f).err())
+                .collect(),
+            Err(error) => vec![CatalogError::Io {
+                path: dir.to_path_buf(),
+                error,
+            }],
+        }
+    }
+
+    /// Reload files that changed or appeared since they were last read.
+    /// Returns the job types that were (re)defined, and any errors.
+    pub fn reload_changed(&mut self) -> (Vec<String>, Vec<CatalogError>) {
+        let mut candidates: Vec<PathBuf> = self.files.keys().cloned().collect();
+        for dir in &self.dirs {
+            candidates.extend(ron_files(dir).unwrap_or_default());
+        }
+        candidates.sort();
+        candidates.dedup();
+
+        let (mut names, mut errors) = (Vec::new(), Vec::new());
+        for path in candidates {
+            let known = self.files.get(&path).copied();
+            let now = modified(&path);
+            if known.is_some_and(|t| t == now) || now.is_none() {
+                continue;
+            }
+            match self.load_file(&path) {
+                Ok(mut n) => names.append(&mut n),
+                Err(e) => errors.push(e),
+            }
+        }
+        (names, errors)
+    }
+
+    fn template(&self, def: &JobDef, origin: &str) -> Result<JobTemplate, Vec<String>> {
+        let mut problems = Vec::new();
+        if def.roles.is_empty() {
+            problems.push(format!("{}: has no roles", def.name));
+        }
+        let mut roles = Vec::new();
+        for (i, r) in def.roles.iter().enumerate() {
+            let at = if r.name.is_empty() {
+                format!("{}/role {i}", def.name)
+            } else {
+                format!("{}/{}", def.name, r.name)
+            };
+            let role_ref = |name: &str, field: &str| match def
+                .roles
+                .iter()
+                .position(|other| other.name == name)
+            {
+                _ if name.is_empty() => Ok(None),
+                Some(j) if j == i => Err(format!("{at}: {field} names the role itself")),
+                Some(j) => Ok(Some(j)),
+                None => Err(format!("{at}: {field} names unknown role {name:?}")),
+            };
+            if r.max == 0 {
+                problems.push(format!("{at}: max must be at least 1"));
+            }
+            if r.min > r.max {
+                problems.push(format!("{at}: min {} exceeds max {}", r.min, r.max));
+            }
+            if !r.wage.is_finite() || r.wage < 0.0 {
+                problems.push(format!("{at}: wage {} must be zero or more", r.wage));
+            }
+            let mut constraints = Vec::new();
+            for c in &r.constraints {
+                match self.constraint(c) {
+                    Ok(c) => constraints.push(c),
+                    Err(p) => problems.push(format!("{at}: {p}")),
+                }
+            }
+            if !r.schooling.is_finite() || r.schooling < 0.0 {
+                problems.push(format!(
+                    "{at}: schooling {} must be zero or more",
+                    r.schooling
+                ));
+            }
+            match r.vacancy_policy {
+                VacancyPolicy::Target(share) if !(0.0..=1.0).contains(&share) => {
+                    problems.push(format!("{at}: Target({share}) is outside 0..=1"));
+                }
+                VacancyPolicy::Budget(years) if !years.is_finite() || years < 0.0 => {
+                    problems.push(format!("{at}: Budget({years}) must be zero or more years"));
+                }
+                _ => {}
+            }
+            for recipe in &r.produces {
+                let out = &recipe.output;
+                if out.trim().is_empty() {
+                    problems.push(format!("{at}: produces an item with an empty name"));
+                }
+                if !recipe.rate.is_finite() || recipe.rate < 0.0 {
+                    problems.push(format!(
+                        "{at}: {out} rate {} must be zero or more",
+                        recipe.rate
+                    ));
+                }
+                for (kind, count) in &recipe.inputs {
+                    if kind.trim().is_empty() || *count == 0 {
+                        problems.push(format!("{at}: {out} input {kind:?} x{count} is empty"));
+                    }
+                }
+            }
+            if r.trains.iter().any(|t| t.trim().is_empty()) {
+                problems.push(format!("{at}: trains a skill with an empty name"));
+            }
+            for &(t, target, weight) in &r.prefers {
+                if !(0.0..=1.0).contains(&target) {
+                    problems.push(format!("{at}: {t:?} target {target} is outside 0..=1"));
+                }
+                if weight < 0.0 {
+                    problems.push(format!("{at}: {t:?} weight {weight} is negative"));
+                }
+            }
+            let mut link = |name: &str, field: &str| {
+                role_ref(name, field).unwrap_or_else(|p| {
+                    problems.push(p);
+                    None
+                })
+            };
+            roles.push(RoleSpec {
+                name: r.name.clone(),
+                min: r.min,
+                max: r.max,
+                constraints,
+                desired: (!r.prefers.is_empty()).then(|| TraitProfile {
+                    targets: r.prefers.clone(),
+                }),
+                wage: r.wage,
+                trains: r.trains.clone(),
+                reports_to: link(&r.reports_to, "reports_to"),
+                promotes_to: link(&r.promotes_to, "promotes_to"),
+                schooling: r.schooling,
+                compulsory: r.compulsory,
+                vacancy_policy: r.vacancy_policy,
+                produces: r.produces.clone(),
+            });
+        }
+        if promotion_cycle(&roles) {
+            problems.push(format!("{}: promotion path loops", def.name));
+        }
+        if problems.is_empty() {
+            Ok(JobTemplate {
+                name: def.name.clone(),
+                origin: origin.to_string(),
+                roles,
+            })
+        } else {
+            Err(problems)
+        }
+    }
+
+    fn constraint(&self, def: &ConstraintDef) -> Result<Constraint, String> {
+        let unit = |t: Trait, v: f64| {
+            if (0.0..=1.0).contains(&v) {
+                Ok(())
+            } else {
+                Err(format!("{t:?} threshold {v} is outside 0..=1"))
+            }
+        };
+        let all = |cs: &[ConstraintDef], op: &str| {
+            if cs.is_empty() {
+                return Err(format!("{op} needs at least one constraint"));
+            }
+            cs.iter().map(|c| self.constraint(c)).collect()
+        };
+        Ok(match def {
+            ConstraintDef::AgeLessThan(n) => Constraint::AgeLessThan(*n),
+            ConstraintDef::AgeAtLeast(n) => Constraint::AgeAtLeast(*n),
+            ConstraintDef::AgeBetween(lo, hi) => {
+                if lo >= hi {
+                    return Err(format!("AgeBetween({lo}, {hi}) admits no age"));
+                }
+                Constraint::AgeBetween(*lo, *hi)
+            }
+            ConstraintDef::TraitAtLeast(t, v) => {
+                unit(*t, *v)?;
+                Constraint::TraitAtLeast(*t, *v)
+            }
+            ConstraintDef::TraitAtMost(t, v) => {
+                unit(*t, *v)?;
+                Constraint::TraitAtMost(*t, *v)
+            }
+            ConstraintDef::MinEducation(y) => {
+                if *y < 0.0 {
+                    return Err(format!("MinEducation({y}) is negative"));
+                }
+                Constraint::MinEducation(*y)
+            }
+            ConstraintDef::MinSkill(name, l) => {
+                if !(0.0..=1.0).contains(l) {
+                    return Err(format!("{name} skill level {l} is outside 0..=1"));
+                }
+                Constraint::MinSkill(name.clone(), *l)
+            }
+            ConstraintDef::Sex(s) => Constraint::Sex(*s),
+            ConstraintDef::LivesWithin { x, y, radius } => {
+                if *radius < 0.0 {
+                    return Err(format!("LivesWithin radius {radius} is negative"));
+                }
+                Constraint::LivesWithin {
+                    x: *x,
+                    y: *y,
+                    radius: *radius,
+                }
+            }
+            ConstraintDef::Has(name) => match self.components.get(name) {
+                Some(check) => Constraint::Has(*check),
+                None => {
+                    let mut known: Vec<&str> = self.components.keys().map(String::as_str).collect();
+                    known.sort();
+                    return Err(format!(
+                        "unknown component {name:?} (known: {})",
+                        known.join(", ")
+                    ));
+                }
+            },
+            ConstraintDef::And(cs) => Constraint::And(all(cs, "And")?),
+            ConstraintDef::Or(cs) => Constraint::Or(all(cs, "Or")?),
+            ConstraintDef::Not(c) => Constraint::Not(Box::new(self.constraint(c)?)),
+        })
+    }
+}
@@ -2,20 +2,136 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::prelude::*;
 
-/// Declarative constraints for a role. Pure data.
-#[derive(Clone, Debug)]
-pub enum Constraint {
-    AgeLessThan(u8),
-    AgeAtLeast(u8),
-    // add more later (HasTrait(...), LivesIn(...), etc.)
+use crate::jobs::constraint::{check_all, Rejection};
+use crate::person::{Sex, Skills};
+use crate::personality::{Personality, Trait};
+
+pub use crate::jobs::constraint::Constraint;
+
+/// Desired personality for a role: per-trait targets with weights.
+#[derive(Clone, Debug, Default)]
+pub struct TraitProfile {
+    pub targets: Vec<(Trait, f64, f64)>, // (trait, target, weight)
+}
+
+impl TraitProfile {
+    /// Weighted closeness to the targets in `[0, 1]`; 1 is a perfect match.
+    /// Assumes traits on the default `[0, 1]` scale.
+    pub fn fit(&self, p: &Personality) -> f64 {
+        let total: f64 = self.targets.iter().map(|&(_, _, w)| w.max(0.0)).sum();
+        if total <= 0.0 {
+            return 1.0;
+        }
+        let miss: f64 = self
+            .targets
+            .iter()
+            .map(|&(t, target, w)| w.max(0.0) * (p.get(t) - target).abs().min(1.0))
+            .sum();
+        1.0 - miss / total
+    }
+}
+
+/// How many seats above `min` a role advertises.
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Deserialize)]
+pub enum VacancyPolicy {
+    /// Only the seats below `min`.
+    #[default]
+    Minimum,
+    /// Up to this share of `max`; 1 fills every seat.
+    Target(f32),
+    /// Up to `max`, while the job's funds cover its payroll, new seats
+    /// included, for this many years.
+    Budget(f32),
+}
+
+/// Open seats in a role: `required` to reach `min`, and `optional` ones
+/// above it that its `VacancyPolicy` allows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Vacancy {
+    pub required: u32,
+    pub optional: u32,
+}
+
+impl Vacancy {
+    pub fn total(&self) -> u32 {
+        self.required + self.optional
+    }
+}
+
+/// What a job can spend on new seats: its balance and current yearly wage
+/// bill (see `Job::payroll`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Funds {
+    pub balance: f64,
+    pub payroll: f64,
+}
+
+/// Something a role makes: `rate` units of `output` per member-year at
+/// average productivity, each using up `inputs` from the job's `Inventory`.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Recipe {
+    pub output: String,
+    pub rate: f32,
+    /// `(item kind, count)` consumed per unit made.
+    #[serde(default)]
+    pub inputs: Vec<(String, u32)>,
 }
 
 /// A role *definition* inside a Job: seats + constraints.
 #[derive(Clone, Debug)]
 pub struct RoleSpec {
+    /// Catalog name; empty for unnamed roles and builder jobs.
+    pub name: String,
     pub min: u32,
     pub max: u32,
     pub constraints: Vec<Constraint>,
+    /// Applicants are ranked by fit to this profile when set.
+    pub desired: Option<TraitProfile>,
+    /// Pay per simulated year for each seat holder.
+    pub wage: f32,
+    /// Skills members practise, and applicants are ranked by.
+    pub trains: Vec<String>,
+    /// Index of the role that supervises this one.
+    pub reports_to: Option<usize>,
+    /// Index of the role members are promoted into when it has a vacancy.
+    pub promotes_to: Option<usize>,
+    /// Years of `Education` gained per simulated year in the role; nonzero
+    /// makes it a student role whose members graduate once it no longer
+    /// admits them.
+    pub schooling: f32,
+    /// Everyone the role admits is enrolled in it rather than applying,
+    /// and cannot quit or be dismissed.
+    pub compulsory: bool,
+    /// How many seats above `min` are advertised.
+    pub vacancy_policy: VacancyPolicy,
+    /// What members make; their time is split evenly between recipes.
+    pub produces: Vec<Recipe>,
+}
+
+impl RoleSpec {
+    /// Whether `e` meets every constraint, with the reasons if not.
+    pub fn admits(&self, e: &EntityRef) -> Result<(), Rejection> {
+        check_all(&self.constraints, e)
+    }
+
+    /// Mean level of the trained skills in `[0, 1]`; 0 when the role trains
+    /// none or the applicant has no `Skills`.
+    pub fn skill_match(&self, skills: Option<&Skills>) -> f64 {
+        match skills {
+            Some(s) if !self.trains.is_empty() => {
+                self.trains.iter().map(|t| s.level(t) as f64).sum::<f64>()
+                    / self.trains.len() as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Fit of an applicant to `desired`; `None` if the role has no profile
+    /// or the applicant has no personality.
+    pub fn fit(&self, p: Option<&Personality>) -> Option<f64> {
+        Some(self.desired.as_ref()?.fit(p?))
+    }
 }
 
 /// A Job is a list of role definitions paired with their members.
@@ -24,6 +140,41 @@ pub struct Job {
     pub roles: Vec<(RoleSpec, Vec<Entity>)>, // (spec, members)
 }
 
+/// Where a person works: the person-side mirror of their seat in
+/// `Job::roles`, so lookups need not scan every job, and how they came by it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Employment {
+    pub job: Entity,
+    pub role_index: usize,
+    /// Simulated years elapsed (see `AgingConfig::years`) when hired.
+    pub since: f64,
+    /// Personality fit to the role; `None` when the role declares no `TraitProfile`.
+    pub fit: Option<f64>,
+    /// Member of the job who passed the vacancy on, if any.
+    pub referred_by: Option<Entity>,
+}
+
+impl Employment {
+    pub fn new(job: Entity, role_index: usize) -> Self {
+        Self {
+            job,
+            role_index,
+            since: 0.0,
+            fit: None,
+            referred_by: None,
+        }
+    }
+}
+
+/// Name of the catalog entry a `Job` was instantiated from.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct JobType(pub String);
+
+/// Temporarily not attending any role (e.g. off sick). The person stays a
+/// member of their `Job`, but is skipped by workplace contact.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Absent;
+
 /// Fluent builder so you can write ontology-like lines in `main`.
 pub struct JobBuilder {
     roles: Vec<(RoleSpec, Vec<Entity>)>,
@@ -37,6 +188,51 @@ impl Job {
             current: None,
         }
     }
+
+    /// Every `(role_index, member)` pair.
+    pub fn members(&self) -> impl Iterator<Item = (usize, Entity)> + '_ {
+        self.roles
+            .iter()
+            .enumerate()
+            .flat_map(|(i, (_, members))| members.iter().map(move |&m| (i, m)))
+    }
+
+    /// The role `person` holds here, if any.
+    pub fn role_of(&self, person: Entity) -> Option<usize> {
+        self.roles
+            .iter()
+            .position(|(_, members)| members.contains(&person))
+    }
+
+    pub fn headcount(&self) -> usize {
+        self.roles.iter().map(|(_, members)| members.len()).sum()
+    }
+
+    /// Yearly wages of everyone currently holding a seat.
+    pub fn payroll(&self) -> f64 {
+        self.roles
+            .iter()
+            .map(|(spec, members)| spec.wage as f64 * members.len() as f64)
+            .sum()
+    }
+
+    /// Members of the role that role `role_index` reports to.
+    pub fn supervisors(&self, role_index: usize) -> &[Entity] {
+        self.roles
+            .get(role_index)
+            .and_then(|(spec, _)| spec.reports_to)
+            .and_then(|r| self.roles.get(r))
+            .map_or(&[], |(_, members)| members.as_slice())
+    }
+
+    /// Roles whose members are promoted into `role_index`.
+    pub fn feeders(&self, role_index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.roles
+            .iter()
+            .enumerate()
+            .filter(move |(_, (spec, _))| spec.promotes_to == Some(role_index))
+            .map(|(i, _)| i)
+    }
 }
 
 impl JobBuilder {
@@ -44,9 +240,19 @@ impl JobBuilder {
     pub fn add_role(mut self, min: u32, max: u32) -> Self {
         self.roles.push((
             RoleSpec {
+                name: String::new(),
                 min,
                 max,
                 constraints: Vec::new(),
+                desired: None,
+                wage: 0.0,
+                trains: Vec::new(),
+                reports_to: None,
+                promotes_to: None,
+                schooling: 0.0,
+                compulsory: false,
+                vacancy_policy: VacancyPolicy::Minimum,
+                produces: Vec::new(),
             },
             Vec::new(),
         ));
@@ -62,6 +268,100 @@ impl JobBuilder {
         self
     }
 
+    /// Ask the most recently added role for `target` on trait `t`, with `weight`.
+    pub fn prefers(mut self, t: Trait, target: f64, weight: f64) -> Self {
+        if let Some(i) = self.current {
+            let profile = self.roles[i]
+                .0
+                .desired
+                .get_or_insert_with(TraitProfile::default);
+            profile.targets.push((t, target, weight));
+        }
+        self
+    }
+
+    /// Set the yearly wage of the most recently added role.
+    pub fn wage(mut self, wage: f32) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.wage = wage;
+        }
+        self
+    }
+
+    /// The most recently added role is supervised by role `index`.
+    pub fn reports_to(mut self, index: usize) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.reports_to = Some(index);
+        }
+        self
+    }
+
+    /// Members of the most recently added role are promoted into role `index`.
+    pub fn promotes_to(mut self, index: usize) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.promotes_to = Some(index);
+        }
+        self
+    }
+
+    /// Members of the most recently added role gain `rate` years of
+    /// education per year.
+    pub fn schooling(mut self, rate: f32) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.schooling = rate;
+        }
+        self
+    }
+
+    /// Everyone the most recently added role admits is enrolled in it.
+    pub fn compulsory(mut self) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.compulsory = true;
+        }
+        self
+    }
+
+    /// Set how many seats above `min` the most recently added role advertises.
+    pub fn vacancy_policy(mut self, policy: VacancyPolicy) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.vacancy_policy = policy;
+        }
+        self
+    }
+
+    /// Members of the most recently added role make `rate` units of
+    /// `output` per member-year.
+    pub fn produces(mut self, output: impl Into<String>, rate: f32) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.produces.push(Recipe {
+                output: output.into(),
+                rate,
+                inputs: Vec::new(),
+            });
+        }
+        self
+    }
+
+    /// Each unit of the most recent `produces` uses up `count` items of `kind`.
+    /// A count of zero is ignored.
+    pub fn consumes(mut self, kind: impl Into<String>, count: u32) -> Self {
+        if count > 0
+            && let Some(i) = self.current
+            && let Some(recipe) = self.roles[i].0.produces.last_mut()
+        {
+            recipe.inputs.push((kind.into(), count));
+        }
+        self
+    }
+
+    /// Members of the most recently added role practise skill `name`.
+    pub fn trains(mut self, name: impl Into<String>) -> Self {
+        if let Some(i) = self.current {
+            self.roles[i].0.trains.push(name.into());
+        }
+        self
+    }
+
     // Sugar: ontologically readable methods (you can add more later)
     pub fn age_lt(self, n: u8) -> Self {
         self.with_constraint(Constraint::AgeLessThan(n))
@@ -70,12 +370,59 @@ impl JobBuilder {
         self.with_constraint(Constraint::AgeAtLeast(n))
     }
 
+    pub fn age_between(self, lo: u8, hi: u8) -> Self {
+        self.with_constraint(Constraint::AgeBetween(lo, hi))
+    }
+    pub fn trait_at_least(self, t: Trait, v: f64) -> Self {
+        self.with_constraint(Constraint::TraitAtLeast(t, v))
+    }
+    pub fn education(self, years: f32) -> Self {
+        self.with_constraint(Constraint::MinEducation(years))
+    }
+    pub fn skill(self, name: impl Into<String>, level: f32) -> Self {
+        self.with_constraint(Constraint::MinSkill(name.into(), level))
+    }
+    pub fn sex(self, s: Sex) -> Self {
+        self.with_constraint(Constraint::Sex(s))
+    }
+    pub fn requires<T: Component>(self) -> Self {
+        self.with_constraint(Constraint::has::<T>())
+    }
+
     /// Finalize into a Job component.
     pub fn build(self) -> Job {
         Job { roles: self.roles }
     }
 }
 
-pub fn vacancy(role: &(RoleSpec, Vec<Entity>)) -> u32 {
-    role.0.min.saturating_sub(role.1.len() as u32)
+/// Open seats in `role`. `Budget` roles only open optional seats when the
+/// job's `funds` are known, or the role pays nothing.
+pub fn vacancy(role: &(RoleSpec, Vec<Entity>), funds: Option<Funds>) -> Vacancy {
+    let (spec, members) = role;
+    let filled = members.len() as u32;
+    let min = spec.min.min(spec.max);
+    let required = min.saturating_sub(filled);
+    let room = spec.max.saturating_sub(filled.max(min));
+    let optional = match spec.vacancy_policy {
+        VacancyPolicy::Minimum => 0,
+        VacancyPolicy::Target(share) => {
+            let target = (spec.max as f32 * share.clamp(0.0, 1.0)).ceil() as u32;
+            target.saturating_sub(filled.max(min))
+        }
+        VacancyPolicy::Budget(_) if spec.wage <= 0.0 => room,
+        VacancyPolicy::Budget(years) => funds.map_or(0, |f| {
+            let years = years.max(0.0) as f64;
+            let wage = spec.wage as f64;
+            let spare = f.balance - years * (f.payroll + required as f64 * wage);
+            if years == 0.0 {
+                if spare >= 0.0 { room } else { 0 }
+            } else {
+                (spare / (years * wage)).floor().clamp(0.0, room as f64) as u32
+            }
+        }),
+    };
+    Vacancy {
+        required,
+        optional: optional.min(room),
+    }
 }
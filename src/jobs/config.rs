@@ -0,0 +1,8 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct JobCatalogConfig {
+    /// Real seconds between checks for edited job files; `None` disables
+    /// hot reload.
+    pub reload_secs: Option<f64>,
+}
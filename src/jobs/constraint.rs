@@ -0,0 +1,216 @@
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::EntityRef;
+use std::fmt;
+
+use crate::hiring_manager::component::Age;
+use crate::person::{Education, Person, Position, Sex, Skills};
+use crate::personality::{Personality, Trait};
+
+/// Presence test for an arbitrary component, built with `Constraint::has`.
+#[derive(Clone, Copy)]
+pub struct ComponentCheck {
+    pub name: &'static str,
+    check: fn(&EntityRef) -> bool,
+}
+
+impl fmt::Debug for ComponentCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Has({})", self.name)
+    }
+}
+
+fn present<T: Component>(e: &EntityRef) -> bool {
+    e.contains::<T>()
+}
+
+/// Declarative constraints for a role. Pure data; evaluate with `check`.
+#[derive(Clone, Debug)]
+pub enum Constraint {
+    AgeLessThan(u8),
+    AgeAtLeast(u8),
+    /// `lo <= age < hi`.
+    AgeBetween(u8, u8),
+    TraitAtLeast(Trait, f64),
+    TraitAtMost(Trait, f64),
+    /// At least this many years of `Education`.
+    MinEducation(f32),
+    /// `Skills` level for the named skill.
+    MinSkill(String, f32),
+    Sex(Sex),
+    /// `Position` within `radius` of `(x, y)`.
+    LivesWithin {
+        x: f32,
+        y: f32,
+        radius: f32,
+    },
+    Has(ComponentCheck),
+    And(Vec<Constraint>),
+    Or(Vec<Constraint>),
+    Not(Box<Constraint>),
+}
+
+/// Why an applicant does not satisfy a constraint, one line per unmet part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rejection {
+    pub reasons: Vec<String>,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reasons.join("; "))
+    }
+}
+
+// Years from `Person`, falling back to the hiring `Age` component.
+fn age_of(e: &EntityRef) -> Option<f32> {
+    e.get::<Person>()
+        .map(|p| p.age)
+        .or_else(|| e.get::<Age>().map(|a| a.years as f32))
+}
+
+fn trait_name(t: Trait) -> String {
+    format!("{t:?}").to_lowercase()
+}
+
+impl Constraint {
+    /// Requires component `T` on the applicant.
+    pub fn has<T: Component>() -> Self {
+        let full = std::any::type_name::<T>();
+        Constraint::Has(ComponentCheck {
+            name: full.rsplit("::").next().unwrap_or(full),
+            check: present::<T>,
+        })
+    }
+
+    /// `Ok` if `e` satisfies the constraint, otherwise every reason it does not.
+    pub fn check(&self, e: &EntityRef) -> Result<(), Rejection> {
+        let fail = |r: String| Err(Rejection { reasons: vec![r] });
+        let age = || age_of(e).ok_or_else(|| "age unknown".to_string());
+        let trait_value = |t: Trait| {
+            e.get::<Personality>()
+                .map(|p| p.get(t))
+                .ok_or_else(|| "has no personality".to_string())
+        };
+        match self {
+            Constraint::AgeLessThan(n) => match age() {
+                Ok(a) if a < *n as f32 => Ok(()),
+                Ok(a) => fail(format!("age {a:.0} is not below {n}")),
+                Err(r) => fail(r),
+            },
+            Constraint::AgeAtLeast(n) => match age() {
+                Ok(a) if a >= *n as f32 => Ok(()),
+                Ok(a) => fail(format!("age {a:.0} is below {n}")),
+                Err(r) => fail(r),
+            },
+            Constraint::AgeBetween(lo, hi) => match age() {
+                Ok(a) if a >= *lo as f32 && a < *hi as f32 => Ok(()),
+                Ok(a) => fail(format!("age {a:.0} is outside {lo}..{hi}")),
+                Err(r) => fail(r),
+            },
+            Constraint::TraitAtLeast(t, v) => match trait_value(*t) {
+                Ok(x) if x >= *v => Ok(()),
+                Ok(x) => fail(format!("{} {x:.2} is below {v:.2}", trait_name(*t))),
+                Err(r) => fail(r),
+            },
+            Constraint::TraitAtMost(t, v) => match trait_value(*t) {
+                Ok(x) if x <= *v => Ok(()),
+                Ok(x) => fail(format!("{} {x:.2} is above {v:.2}", trait_name(*t))),
+                Err(r) => fail(r),
+            },
+            Constraint::MinEducation(years) => {
+                let have = e.get::<Education>().map_or(0.0, |ed| ed.years);
+                if have >= *years {
+                    Ok(())
+                } else {
+                    fail(format!("{have:.1} years of education, needs {years:.1}"))
+                }
+            }
+            Constraint::MinSkill(name, level) => {
+                let have = e.get::<Skills>().map_or(0.0, |s| s.level(name));
+                if have >= *level {
+                    Ok(())
+                } else {
+                    fail(format!("{name} skill {have:.2}, needs {level:.2}"))
+                }
+            }
+            Constraint::Sex(want) => match e.get::<Sex>() {
+                Some(s) if s == want => Ok(()),
+                Some(s) => fail(format!("sex is {s:?}, needs {want:?}")),
+                None => fail("sex unknown".into()),
+            },
+            Constraint::LivesWithin { x, y, radius } => match e.get::<Position>() {
+                Some(p) => {
+                    let d = p.distance(&Position::new(*x, *y));
+                    if d <= *radius {
+                        Ok(())
+                    } else {
+                        fail(format!("lives {d:.1} away, limit {radius:.1}"))
+                    }
+                }
+                None => fail("has no position".into()),
+            },
+            Constraint::Has(c) => {
+                if (c.check)(e) {
+                    Ok(())
+                } else {
+                    fail(format!("lacks {}", c.name))
+                }
+            }
+            Constraint::And(all) => check_all(all, e),
+            Constraint::Or(any) => {
+                let mut reasons = Vec::new();
+                for c in any {
+                    match c.check(e) {
+                        Ok(()) => return Ok(()),
+                        Err(r) => reasons.push(r.to_string()),
+                    }
+                }
+                fail(format!("none of: {}", reasons.join(" | ")))
+            }
+            Constraint::Not(inner) => match inner.check(e) {
+                Ok(()) => fail(format!("must not satisfy {inner}")),
+                Err(_) => Ok(()),
+            },
+        }
+    }
+}
+
+/// All constraints must hold; collects the reasons of every one that fails.
+pub fn check_all(constraints: &[Constraint], e: &EntityRef) -> Result<(), Rejection> {
+    let reasons: Vec<String> = constraints
+        .iter()
+        .filter_map(|c| c.check(e).err())
+        .flat_map(|r| r.reasons)
+        .collect();
+    if reasons.is_empty() {
+        Ok(())
+    } else {
+        Err(Rejection { reasons })
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, cs: &[Constraint], op: &str| {
+            let parts: Vec<String> = cs.iter().map(|c| c.to_string()).collect();
+            write!(f, "({})", parts.join(op))
+        };
+        match self {
+            Constraint::AgeLessThan(n) => write!(f, "age < {n}"),
+            Constraint::AgeAtLeast(n) => write!(f, "age >= {n}"),
+            Constraint::AgeBetween(lo, hi) => write!(f, "{lo} <= age < {hi}"),
+            Constraint::TraitAtLeast(t, v) => write!(f, "{} >= {v}", trait_name(*t)),
+            Constraint::TraitAtMost(t, v) => write!(f, "{} <= {v}", trait_name(*t)),
+            Constraint::MinEducation(y) => write!(f, "education >= {y} years"),
+            Constraint::MinSkill(name, l) => write!(f, "{name} skill >= {l}"),
+            Constraint::Sex(s) => write!(f, "sex = {s:?}"),
+            Constraint::LivesWithin { x, y, radius } => {
+                write!(f, "lives within {radius} of ({x}, {y})")
+            }
+            Constraint::Has(c) => write!(f, "has {}", c.name),
+            Constraint::And(cs) => join(f, cs, " and "),
+            Constraint::Or(cs) => join(f, cs, " or "),
+            Constraint::Not(c) => write!(f, "not {c}"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use bevy_ecs::prelude::*;
+
+use crate::person::Position;
+
+/// Spawn `count` jobs of catalog type `name`, optionally sited at `position`.
+#[derive(Event, Debug, Clone)]
+pub struct SpawnJob {
+    pub name: String,
+    pub count: u32,
+    pub position: Option<Position>,
+}
+
+impl SpawnJob {
+    pub fn new(name: impl Into<String>, count: u32) -> Self {
+        Self {
+            name: name.into(),
+            count,
+            position: None,
+        }
+    }
+
+    pub fn at(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+/// Catalog job types redefined by a hot reload; live jobs have been updated.
+#[derive(Event, Debug, Clone)]
+pub struct JobTypesReloaded {
+    pub names: Vec<String>,
+}
+
+/// A catalog reload or a vanished job took `person`'s seat in `role_index`
+/// of `job` away; the roster no longer lists them. Handled by the
+/// separation plugin as a layoff.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LayoffRequest {
+    pub person: Entity,
+    pub job: Entity,
+    pub role_index: usize,
+}
+
+/// `person` moved up from role `from` to role `to` within `job`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Promoted {
+    pub person: Entity,
+    pub job: Entity,
+    pub from: usize,
+    pub to: usize,
+}
@@ -1,5 +1,18 @@
+pub mod catalog;
 pub mod component;
+pub mod config;
+pub mod constraint;
+pub mod events;
 pub mod plugin;
+pub mod system;
 
-pub use component::{Job, RoleSpec, Constraint};
-pub use plugin::JobsPlugin;
+pub use catalog::{CatalogError, ConstraintDef, JobCatalog, JobDef, JobTemplate, RoleDef};
+pub use component::{
+    vacancy, Absent, Employment, Funds, Job, JobType, Recipe, RoleSpec, TraitProfile, Vacancy,
+    VacancyPolicy,
+};
+pub use config::JobCatalogConfig;
+pub use constraint::{check_all, ComponentCheck, Constraint, Rejection};
+pub use events::{JobTypesReloaded, LayoffRequest, Promoted, SpawnJob};
+pub use plugin::{JobCatalogPlugin, JobsPlugin};
+pub use system::{advertise_new_jobs, refresh_job, sync_employment, Refreshed};
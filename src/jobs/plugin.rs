@@ -1,11 +1,80 @@
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::prelude::*;
+use std::path::PathBuf;
 
+use crate::gregslist::component::VacancyDirty;
+use crate::jobs::catalog::JobCatalog;
+use crate::jobs::config::JobCatalogConfig;
+use crate::jobs::events::{JobTypesReloaded, LayoffRequest, SpawnJob};
+use crate::jobs::system::{
+    advertise_new_jobs, reload_job_catalog, spawn_catalog_jobs, sync_employment,
+};
+use crate::person::AgingConfig;
+
+/// Keeps `Employment` and job rosters consistent.
 pub struct JobsPlugin;
 
 impl Plugin for JobsPlugin {
-    fn build(&self, _app: &mut App) {
-        // v0: intentionally empty
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AgingConfig>()
+            .add_event::<VacancyDirty>()
+            .add_event::<LayoffRequest>()
+            .add_systems(PostUpdate, sync_employment);
+    }
+}
+
+/// Loads job types from the `.ron` files in a directory into `JobCatalog`,
+/// spawns the requested instances at startup, and optionally hot reloads.
+pub struct JobCatalogPlugin {
+    dir: PathBuf,
+    spawn: Vec<(String, u32)>,
+    reload_secs: Option<f64>,
+}
+
+impl JobCatalogPlugin {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            spawn: Vec::new(),
+            reload_secs: None,
+        }
+    }
+
+    /// Spawn `count` jobs of type `name` at startup.
+    pub fn spawn(mut self, name: impl Into<String>, count: u32) -> Self {
+        self.spawn.push((name.into(), count));
+        self
+    }
+
+    /// Check the files for edits every `secs` real seconds.
+    pub fn hot_reload(mut self, secs: f64) -> Self {
+        self.reload_secs = Some(secs);
+        self
+    }
+}
+
+impl Plugin for JobCatalogPlugin {
+    fn build(&self, app: &mut App) {
+        let mut catalog = JobCatalog::default();
+        for err in catalog.load_dir(&self.dir) {
+            bevy_log::error!("{err}");
+        }
+        let spawn = self.spawn.clone();
+        app.insert_resource(catalog)
+            .insert_resource(JobCatalogConfig {
+                reload_secs: self.reload_secs,
+            })
+            .add_event::<SpawnJob>()
+            .add_event::<JobTypesReloaded>()
+            .add_event::<VacancyDirty>()
+            .add_event::<LayoffRequest>()
+            .add_systems(Startup, move |mut out: EventWriter<SpawnJob>| {
+                for (name, count) in &spawn {
+                    out.write(SpawnJob::new(name.clone(), *count));
+                }
+            })
+            .add_systems(Update, (reload_job_catalog, spawn_catalog_jobs).chain())
+            .add_systems(PostUpdate, advertise_new_jobs);
     }
 }
@@ -0,0 +1,238 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::HashSet;
+
+use crate::gregslist::component::VacancyDirty;
+use crate::hiring_manager::component::Unemployed;
+use crate::jobs::catalog::{JobCatalog, JobTemplate};
+use crate::jobs::component::{Employment, Job, JobType, RoleSpec};
+use crate::jobs::config::JobCatalogConfig;
+use crate::jobs::events::{JobTypesReloaded, LayoffRequest, SpawnJob};
+use crate::person::AgingConfig;
+use crate::personality::Personality;
+
+/// Instantiate requested job types, tagged with their `JobType`.
+pub fn spawn_catalog_jobs(
+    mut commands: Commands,
+    catalog: Res<JobCatalog>,
+    mut requests: EventReader<SpawnJob>,
+) {
+    for req in requests.read() {
+        let Some(template) = catalog.get(&req.name) else {
+            bevy_log::error!("no job type {:?} in the catalog", req.name);
+            continue;
+        };
+        for _ in 0..req.count {
+            let mut job = commands.spawn((template.instantiate(), JobType(req.name.clone())));
+            if let Some(position) = req.position {
+                job.insert(position);
+            }
+        }
+    }
+}
+
+/// Advertise catalog jobs spawned since the last run; the hiring manager
+/// only seeds postings for jobs that exist at startup.
+pub fn advertise_new_jobs(
+    jobs: Query<Entity, Added<JobType>>,
+    mut dirty: EventWriter<VacancyDirty>,
+) {
+    for job in jobs.iter() {
+        dirty.write(VacancyDirty { job });
+    }
+}
+
+/// What `refresh_job` did to a job's members.
+#[derive(Debug, Default, PartialEq)]
+pub struct Refreshed {
+    /// `(role held, person)` for members who lost their seat.
+    pub released: Vec<(usize, Entity)>,
+    /// `(person, new role)` for members whose role now sits at another index.
+    pub moved: Vec<(Entity, usize)>,
+}
+
+/// Bring a live job in line with its (re)loaded template. Roles are matched
+/// by name, unnamed ones by position. Members keep their seats where their
+/// role still exists, still `admits` them and has room; the rest are
+/// released, most recent hires first.
+pub fn refresh_job(
+    job: &mut Job,
+    template: &JobTemplate,
+    admits: impl Fn(&RoleSpec, Entity) -> bool,
+) -> Refreshed {
+    let mut out = Refreshed::default();
+    let mut live: Vec<Option<Vec<Entity>>> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    for (spec, members) in std::mem::take(&mut job.roles) {
+        names.push(spec.name);
+        live.push(Some(members));
+    }
+    for (j, spec) in template.roles.iter().enumerate() {
+        let held = match spec.name.as_str() {
+            "" => (j < live.len() && names[j].is_empty()).then_some(j),
+            name => names.iter().position(|n| n == name),
+        };
+        let mut members = Vec::new();
+        if let Some(i) = held
+            && let Some(seated) = live[i].take()
+        {
+            for m in seated {
+                if admits(spec, m) {
+                    members.push(m);
+                } else {
+                    out.released.push((i, m));
+                }
+            }
+            while members.len() > spec.max as usize {
+                out.released.extend(members.pop().map(|m| (i, m)));
+            }
+            if i != j {
+                out.moved.extend(members.iter().map(|&m| (m, j)));
+            }
+        }
+        job.roles.push((spec.clone(), members));
+    }
+    for (i, members) in live.into_iter().enumerate() {
+        out.released
+            .extend(members.into_iter().flatten().rev().map(|m| (i, m)));
+    }
+    out
+}
+
+/// Poll job files for edits every `reload_secs` and update live jobs of
+/// the redefined types, requesting layoffs for members whose seats went
+/// away and moving the `Employment` of members whose role changed index.
+/// Invalid edits are logged and the old definition kept.
+#[allow(clippy::too_many_arguments)]
+pub fn reload_job_catalog(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    cfg: Res<JobCatalogConfig>,
+    mut catalog: ResMut<JobCatalog>,
+    mut last: Local<Option<f64>>,
+    mut jobs: Query<(Entity, &JobType, &mut Job)>,
+    people: Query<EntityRef, Without<Job>>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut layoffs: EventWriter<LayoffRequest>,
+    mut reloaded: EventWriter<JobTypesReloaded>,
+) {
+    let Some(every) = cfg.reload_secs else {
+        return;
+    };
+    let now = time.elapsed_secs_f64();
+    if last.is_some_and(|t| now - t < every) {
+        return;
+    }
+    *last = Some(now);
+
+    let (names, errors) = catalog.reload_changed();
+    for err in errors {
+        bevy_log::error!("{err}");
+    }
+    if names.is_empty() {
+        return;
+    }
+    for (e, kind, mut job) in jobs.iter_mut() {
+        let Some(template) = names
+            .contains(&kind.0)
+            .then(|| catalog.get(&kind.0))
+            .flatten()
+        else {
+            continue;
+        };
+        let refreshed = refresh_job(&mut job, template, |spec, m| {
+            people.get(m).is_ok_and(|p| spec.admits(&p).is_ok())
+        });
+        for (role_index, person) in refreshed.released {
+            layoffs.write(LayoffRequest {
+                person,
+                job: e,
+                role_index,
+            });
+        }
+        for (person, role_index) in refreshed.moved {
+            let Ok(p) = people.get(person) else {
+                continue;
+            };
+            if let Some(&emp) = p.get::<Employment>() {
+                commands.entity(person).insert(Employment {
+                    role_index,
+                    fit: job.roles[role_index].0.fit(p.get::<Personality>()),
+                    ..emp
+                });
+            }
+        }
+        dirty.write(VacancyDirty { job: e });
+    }
+    bevy_log::info!("reloaded job types: {}", names.join(", "));
+    reloaded.write(JobTypesReloaded { names });
+}
+
+/// Keep rosters and `Employment` in step both ways. Despawned members leave
+/// their roster; rostered people without a matching `Employment` are given
+/// one; people whose job is gone or no longer lists them lose it, become
+/// `Unemployed` and are reported as a `LayoffRequest`. Jobs are only
+/// borrowed mutably when a dead member has to be removed.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_employment(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    mut jobs: Query<(Entity, &mut Job)>,
+    people: Query<(Option<&Employment>, Option<&Personality>)>,
+    employed: Query<(Entity, &Employment)>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut layoffs: EventWriter<LayoffRequest>,
+) {
+    let stale: Vec<Entity> = jobs
+        .iter()
+        .filter(|(_, job)| job.members().any(|(_, m)| !people.contains(m)))
+        .map(|(e, _)| e)
+        .collect();
+    for e in stale {
+        if let Ok((_, mut job)) = jobs.get_mut(e) {
+            for (_, members) in job.roles.iter_mut() {
+                members.retain(|&m| people.contains(m));
+            }
+            dirty.write(VacancyDirty { job: e });
+        }
+    }
+    let now = aging.years(time.elapsed_secs_f64());
+    let mut seated = HashSet::new();
+    for (e, job) in jobs.iter() {
+        for (i, (spec, members)) in job.roles.iter().enumerate() {
+            for &m in members {
+                seated.insert(m);
+                let Ok((emp, p)) = people.get(m) else {
+                    continue;
+                };
+                if emp.is_some_and(|emp| (emp.job, emp.role_index) == (e, i)) {
+                    continue;
+                }
+                let same_job = emp.filter(|emp| emp.job == e);
+                commands
+                    .entity(m)
+                    .remove::<Unemployed>()
+                    .insert(Employment {
+                        since: same_job.map_or(now, |emp| emp.since),
+                        fit: spec.fit(p),
+                        referred_by: emp.and_then(|emp| emp.referred_by),
+                        ..Employment::new(e, i)
+                    });
+            }
+        }
+    }
+    for (person, emp) in employed.iter() {
+        if !seated.contains(&person) {
+            commands
+                .entity(person)
+                .remove::<Employment>()
+                .insert(Unemployed);
+            layoffs.write(LayoffRequest {
+                person,
+                job: emp.job,
+                role_index: emp.role_index,
+            });
+        }
+    }
+}
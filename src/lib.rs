@@ -3,6 +3,12 @@
 #![allow(unused_variables)]
 
 pub mod baby_spawner;
+pub mod diffusion;
+pub mod economy;
+pub mod education;
+pub mod enrolment;
+pub mod epidemic;
+pub mod firm;
 pub mod game_events;
 pub mod graph;
 pub mod gregslist;
@@ -12,15 +18,32 @@ pub mod jobs;
 pub mod mortality;
 pub mod person;
 pub mod personality;
+pub mod production;
 pub mod records;
+pub mod separation;
+pub mod skills;
+pub mod social;
 #[cfg(feature = "graphics")]
 pub mod view;
 
 pub use baby_spawner::{BabySpawnerConfig, BabySpawnerPlugin};
+pub use diffusion::DiffusionPlugin;
+pub use economy::PayrollPlugin;
+pub use education::EducationPlugin;
+pub use enrolment::EnrolmentPlugin;
+pub use epidemic::EpidemicPlugin;
+pub use firm::FirmPlugin;
+pub use graph::{Graph, RelationshipPlugin};
 pub use gregslist::{Advert, Gregslist, GregslistConfig, GregslistPlugin, VacancyDirty};
 pub use hiring_manager::HiringManagerPlugin;
-pub use jobs::JobsPlugin;
+pub use jobs::{JobCatalogPlugin, JobsPlugin};
 pub use mortality::MortalityPlugin;
+pub use person::PersonPlugin;
+pub use personality::{Personality, PersonalityConfig, PersonalityPlugin};
+pub use production::ProductionPlugin;
 pub use records::RecordsPlugin;
+pub use separation::SeparationPlugin;
+pub use skills::SkillsPlugin;
+pub use social::SocialNetworkPlugin;
 #[cfg(feature = "graphics")]
 pub use records::{VacancyText, VacancyTextPlugin};
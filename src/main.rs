@@ -9,6 +9,12 @@ use bevy_ecs::prelude::*;
 use bevy_time::{Real, Time};
 
 mod baby_spawner;
+mod diffusion;
+mod economy;
+mod education;
+mod enrolment;
+mod epidemic;
+mod firm;
 mod game_events;
 mod graph;
 mod gregslist;
@@ -18,7 +24,11 @@ mod jobs;
 mod mortality;
 mod person;
 mod personality;
+mod production;
 mod records;
+mod separation;
+mod skills;
+mod social;
 #[cfg(feature = "graphics")]
 mod view;
 
@@ -28,8 +38,8 @@ use crate::mortality::system::apply_mortality_with_rate;
 #[cfg(feature = "graphics")]
 use crate::records::VacancyTextPlugin;
 
-use crate::records::{Records, rolling_mean::RollingMean};
-use jobs::Job;
+use crate::person::{AgingConfig, PersonPlugin};
+use crate::records::{CohortStats, EpidemicStats, Records, rolling_mean::RollingMean};
 
 const SEC: f64 = 1.0;
 const MIN: f64 = 60.0 * SEC;
@@ -52,17 +62,6 @@ fn debug_years(time: Res<Time<Real>>) {
     }
 }
 
-fn spawn_jobs(mut commands: Commands) {
-    let school = Job::builder()
-        .add_role(20, 200)
-        .age_lt(18) // students
-        .add_role(1, 10)
-        .age_gte(18) // teachers
-        .build();
-
-    commands.spawn(school);
-}
-
 fn main() {
     let mut app = App::new();
     #[cfg(feature = "graphics")]
@@ -72,27 +71,59 @@ fn main() {
     }
     #[cfg(not(feature = "graphics"))]
     {
-        app.add_plugins(bevy_time::TimePlugin::default());
+        app.add_plugins(bevy_time::TimePlugin);
     }
-    app.add_plugins(BabySpawnerPlugin)
+    let job_catalog = jobs::JobCatalogPlugin::new("assets/jobs").spawn("school", 1);
+    #[cfg(feature = "graphics")]
+    let job_catalog = job_catalog.hot_reload(1.0);
+    app.add_plugins(PersonPlugin)
+        .add_plugins(BabySpawnerPlugin)
         .add_plugins(records::RecordsPlugin)
         .add_plugins(mortality::MortalityPlugin)
+        .add_plugins(personality::PersonalityPlugin)
+        .add_plugins(social::SocialNetworkPlugin)
+        .add_plugins(diffusion::DiffusionPlugin::<social::Tie>::new())
+        .add_plugins(epidemic::EpidemicPlugin)
+        .add_plugins(economy::PayrollPlugin)
         .add_plugins(jobs::JobsPlugin)
+        .add_plugins(job_catalog)
         .add_plugins(gregslist::GregslistPlugin::new(60.0))
-        .add_plugins(hiring_manager::HiringManagerPlugin::new(8));
-    app.add_systems(Startup, spawn_jobs)
+        .add_plugins(hiring_manager::HiringManagerPlugin::new(8))
+        .add_plugins(separation::SeparationPlugin)
+        .add_plugins(skills::SkillsPlugin)
+        .add_plugins(education::EducationPlugin)
+        .add_plugins(enrolment::EnrolmentPlugin)
+        .add_plugins(production::ProductionPlugin)
+        .add_plugins(firm::FirmPlugin::new().found("farm"));
+    // exporting is opt-in: SIMRS_EXPORT_DIR=exports cargo run
+    if let Ok(dir) = std::env::var("SIMRS_EXPORT_DIR") {
+        app.add_plugins(
+            graph::GraphExportPlugin::<social::Tie>::new(dir, "social")
+                .every(10.0 * YR / SPEED)
+                .attributes(social::person_attributes),
+        );
+    }
+    app
         //.add_systems(Startup, |mut time: ResMut<Time<Real>>| {
         //    time.set_relative_speed(DAY as f32);
         //})
         .insert_resource(BabySpawnerConfig {
             per_sec: BIRTHS_PER_YEAR / YR * SPEED,
+            ..Default::default()
+        })
+        .insert_resource(AgingConfig {
+            years_per_sec: SPEED / YR,
+        })
+        .insert_resource(epidemic::EpidemicConfig {
+            initial_infections: 5,
+            ..Default::default()
         })
         .insert_resource(Records {
-            births: 0,
-            deaths: 0,
             birth_rate: RollingMean::new(DAY),
             death_rate: RollingMean::new(DAY),
-            employment_rate: 0.0,
+            trait_cohorts: CohortStats::new(YR / SPEED),
+            epidemic: EpidemicStats::new(7.0 * DAY / SPEED),
+            ..Default::default()
         })
         .add_systems(Update, {
             let deaths_per_sec_per_person = SPEED / (AVERAGE_LIFESPAN_YEARS * YR);
@@ -0,0 +1,5 @@
+use bevy_ecs::prelude::*;
+
+/// Scales the age hazard for this entity (e.g. while ill). The product is capped at 1.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HazardMultiplier(pub f64);
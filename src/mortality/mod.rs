@@ -1,7 +1,9 @@
+pub mod component;
 pub mod events;
 pub mod plugin;
 pub mod system;
 
+pub use component::HazardMultiplier;
 pub use events::Death;
 pub use plugin::MortalityPlugin;
 pub use system::hazard;
@@ -6,6 +6,7 @@ use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
 use crate::baby_spawner::system::GameRNG; // your RNG resource
+use crate::mortality::component::HazardMultiplier;
 use crate::mortality::events::Death;
 use crate::person::Person;
 
@@ -17,21 +18,27 @@ pub fn hazard(_age: u16) -> f32 {
 }
 
 /// Every time step, with a given probability, kill an entity.
+/// `HazardMultiplier` scales the age hazard.
+#[allow(clippy::type_complexity)]
 pub fn apply_mortality_with_rate(
     _rate_per_tick: f64,
-) -> impl FnMut(ResMut<GameRNG>, ResMut<MortalityTick>, Query<(Entity, &Person)>, EventWriter<Death>)
-       + Send
-       + Sync
-       + 'static {
+) -> impl FnMut(
+    ResMut<GameRNG>,
+    ResMut<MortalityTick>,
+    Query<(Entity, &Person, Option<&HazardMultiplier>)>,
+    EventWriter<Death>,
+) + Send
++ Sync
++ 'static {
     move |mut rng: ResMut<GameRNG>,
           mut tick: ResMut<MortalityTick>,
-          people: Query<(Entity, &Person)>,
+          people: Query<(Entity, &Person, Option<&HazardMultiplier>)>,
           mut writer: EventWriter<Death>| {
         let seed = rng.0.next_u64();
         let current_tick = tick.0;
         tick.0 = tick.0.wrapping_add(1);
-        for (e, person) in people.iter() {
-            let h = hazard(person.age as u16) as f64;
+        for (e, person, multiplier) in people.iter() {
+            let h = (hazard(person.age as u16) as f64 * multiplier.map_or(1.0, |m| m.0)).min(1.0);
             if h <= 0.0 {
                 continue;
             }
@@ -1,10 +1,18 @@
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::prelude::*;
+use bevy_time::Real;
+use std::collections::HashMap;
 
 #[derive(Component)]
 pub struct Person {
-    pub age: f32, // years; will be updated by an aging system later
+    pub age: f32, // years; advanced by `age_people`
+}
+
+impl Default for Person {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Person {
@@ -12,3 +20,100 @@ impl Person {
         Self { age: 0.0 }
     }
 }
+
+/// The two people a newborn was born to. Order carries no meaning.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Parents(pub Entity, pub Entity);
+
+impl Parents {
+    pub fn contains(&self, e: Entity) -> bool {
+        self.0 == e || self.1 == e
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize)]
+pub enum Sex {
+    Female,
+    Male,
+}
+
+/// Where a person lives, or a job is sited, in map units.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Position {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn distance(&self, other: &Position) -> f32 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+/// Years of completed schooling.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Education {
+    pub years: f32,
+}
+
+/// Skill levels by name, each in `[0, 1]`.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Skills(pub HashMap<String, f32>);
+
+impl Skills {
+    pub fn level(&self, name: &str) -> f32 {
+        self.0.get(name).copied().unwrap_or(0.0)
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, level: f32) {
+        self.0.insert(name.into(), level.clamp(0.0, 1.0));
+    }
+}
+
+/// Simulated years spent employed, summed over every job held.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct Experience {
+    pub years: f32,
+}
+
+/// How fast simulated years pass relative to `Time<Real>`.
+#[derive(Resource)]
+pub struct AgingConfig {
+    pub years_per_sec: f64,
+}
+
+impl Default for AgingConfig {
+    fn default() -> Self {
+        Self { years_per_sec: 0.0 }
+    }
+}
+
+impl AgingConfig {
+    /// Simulated years in `secs` of `Time<Real>`.
+    pub fn years(&self, secs: f64) -> f64 {
+        secs * self.years_per_sec
+    }
+}
+
+pub fn age_people(time: Res<Time<Real>>, cfg: Res<AgingConfig>, mut people: Query<&mut Person>) {
+    let dy = (time.delta_secs_f64() * cfg.years_per_sec) as f32;
+    if dy <= 0.0 {
+        return;
+    }
+    for mut p in people.iter_mut() {
+        p.age += dy;
+    }
+}
+
+pub struct PersonPlugin;
+
+impl Plugin for PersonPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AgingConfig>()
+            .add_systems(Update, age_people);
+    }
+}
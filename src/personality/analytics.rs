@@ -0,0 +1,252 @@
+use bevy_ecs::prelude::*;
+use rand::Rng;
+
+use crate::personality::component::{Personality, TRAIT_COUNT};
+
+pub type Matrix = [[f64; TRAIT_COUNT]; TRAIT_COUNT];
+
+/// How two personalities are compared.
+#[derive(Clone, Debug)]
+pub enum Metric {
+    Euclidean,
+    /// `1 - cos(angle)` between the trait vectors; 0 for identical directions.
+    Cosine,
+    /// Holds the inverse of the population covariance.
+    Mahalanobis(Box<Matrix>),
+}
+
+impl Metric {
+    /// Mahalanobis metric for `population`, or `None` if its covariance is singular
+    /// (e.g. fewer people than traits, or a trait with no variance).
+    pub fn mahalanobis<'a>(population: impl IntoIterator<Item = &'a Personality>) -> Option<Self> {
+        let points: Vec<_> = population.into_iter().map(|p| p.as_array()).collect();
+        invert(&covariance(&points)).map(|inv| Metric::Mahalanobis(Box::new(inv)))
+    }
+
+    pub fn distance(&self, a: &Personality, b: &Personality) -> f64 {
+        let (a, b) = (a.as_array(), b.as_array());
+        match self {
+            Metric::Euclidean => euclidean(&a, &b),
+            Metric::Cosine => cosine_distance(&a, &b),
+            Metric::Mahalanobis(inv) => mahalanobis(&a, &b, inv),
+        }
+    }
+}
+
+pub fn euclidean(a: &[f64; TRAIT_COUNT], b: &[f64; TRAIT_COUNT]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f64>().sqrt()
+}
+
+pub fn cosine_distance(a: &[f64; TRAIT_COUNT], b: &[f64; TRAIT_COUNT]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let na = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let nb = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if na == 0.0 || nb == 0.0 {
+        return 1.0;
+    }
+    1.0 - dot / (na * nb)
+}
+
+pub fn mahalanobis(a: &[f64; TRAIT_COUNT], b: &[f64; TRAIT_COUNT], inv_cov: &Matrix) -> f64 {
+    let d: [f64; TRAIT_COUNT] = std::array::from_fn(|i| a[i] - b[i]);
+    let q: f64 = (0..TRAIT_COUNT)
+        .map(|i| d[i] * (0..TRAIT_COUNT).map(|j| inv_cov[i][j] * d[j]).sum::<f64>())
+        .sum();
+    q.max(0.0).sqrt()
+}
+
+/// Sample covariance (n - 1 denominator); zeros for fewer than two points.
+pub fn covariance(points: &[[f64; TRAIT_COUNT]]) -> Matrix {
+    let n = points.len();
+    if n < 2 {
+        return [[0.0; TRAIT_COUNT]; TRAIT_COUNT];
+    }
+    let mean = centroid(points.iter());
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            points
+                .iter()
+                .map(|p| (p[i] - mean[i]) * (p[j] - mean[j]))
+                .sum::<f64>()
+                / (n - 1) as f64
+        })
+    })
+}
+
+/// Gauss–Jordan inverse with partial pivoting; `None` if singular.
+pub fn invert(m: &Matrix) -> Option<Matrix> {
+    let mut a = *m;
+    let mut inv: Matrix = std::array::from_fn(|i| std::array::from_fn(|j| (i == j) as u8 as f64));
+    for col in 0..TRAIT_COUNT {
+        let pivot = (col..TRAIT_COUNT).max_by(|&r, &s| a[r][col].abs().total_cmp(&a[s][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        inv.swap(col, pivot);
+        let p = a[col][col];
+        for j in 0..TRAIT_COUNT {
+            a[col][j] /= p;
+            inv[col][j] /= p;
+        }
+        for row in 0..TRAIT_COUNT {
+            if row != col {
+                let f = a[row][col];
+                for j in 0..TRAIT_COUNT {
+                    a[row][j] -= f * a[col][j];
+                    inv[row][j] -= f * inv[col][j];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+fn centroid<'a>(points: impl Iterator<Item = &'a [f64; TRAIT_COUNT]>) -> [f64; TRAIT_COUNT] {
+    let mut sum = [0.0; TRAIT_COUNT];
+    let mut n = 0usize;
+    for p in points {
+        for (s, x) in sum.iter_mut().zip(p) {
+            *s += x;
+        }
+        n += 1;
+    }
+    if n > 0 {
+        sum.iter_mut().for_each(|s| *s /= n as f64);
+    }
+    sum
+}
+
+/// The `k` agents in `pool` closest to `target` under `metric`, nearest first.
+/// `target` itself is skipped if it appears in the pool.
+pub fn nearest_neighbours(
+    target: (Entity, &Personality),
+    pool: &[(Entity, Personality)],
+    k: usize,
+    metric: &Metric,
+) -> Vec<(Entity, f64)> {
+    let mut scored: Vec<_> = pool
+        .iter()
+        .filter(|(e, _)| *e != target.0)
+        .map(|(e, p)| (*e, metric.distance(target.1, p)))
+        .collect();
+    scored.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(&b.0)));
+    scored.truncate(k);
+    scored
+}
+
+/// A cluster centre in trait space and how many agents fell into it.
+#[derive(Clone, Debug)]
+pub struct Archetype {
+    pub centroid: Personality,
+    pub size: usize,
+}
+
+/// Result of `kmeans`: `assignments[i]` indexes into `centroids`.
+#[derive(Clone, Debug)]
+pub struct Clusters {
+    pub centroids: Vec<[f64; TRAIT_COUNT]>,
+    pub assignments: Vec<usize>,
+}
+
+impl Clusters {
+    /// Clusters as archetypes, largest first.
+    pub fn archetypes(&self) -> Vec<Archetype> {
+        let mut out: Vec<_> = self
+            .centroids
+            .iter()
+            .zip(self.sizes())
+            .map(|(c, size)| Archetype {
+                centroid: Personality::from_array(*c),
+                size,
+            })
+            .collect();
+        out.sort_by_key(|a| std::cmp::Reverse(a.size));
+        out
+    }
+
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.centroids.len()];
+        for &a in &self.assignments {
+            sizes[a] += 1;
+        }
+        sizes
+    }
+}
+
+/// Lloyd's k-means with k-means++ seeding, in Euclidean trait space.
+/// `k` is capped at the number of points.
+pub fn kmeans<R: Rng + ?Sized>(
+    points: &[[f64; TRAIT_COUNT]],
+    k: usize,
+    iterations: usize,
+    rng: &mut R,
+) -> Clusters {
+    let k = k.min(points.len());
+    if k == 0 {
+        return Clusters {
+            centroids: Vec::new(),
+            assignments: Vec::new(),
+        };
+    }
+
+    // k-means++: each next centroid picked with probability ∝ squared distance
+    let mut centroids = vec![points[rng.random_range(0..points.len())]];
+    while centroids.len() < k {
+        let d2: Vec<f64> = points
+            .iter()
+            .map(|p| {
+                centroids
+                    .iter()
+                    .map(|c| euclidean(p, c).powi(2))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect();
+        let total: f64 = d2.iter().sum();
+        if total <= 0.0 {
+            break; // fewer distinct points than k
+        }
+        let mut u = rng.random::<f64>() * total;
+        let pick = d2
+            .iter()
+            .position(|&d| {
+                u -= d;
+                u <= 0.0
+            })
+            .unwrap_or(points.len() - 1);
+        centroids.push(points[pick]);
+    }
+
+    let mut assignments = vec![usize::MAX; points.len()];
+    for _ in 0..iterations.max(1) {
+        let mut changed = false;
+        for (i, p) in points.iter().enumerate() {
+            let best = (0..centroids.len())
+                .min_by(|&a, &b| euclidean(p, &centroids[a]).total_cmp(&euclidean(p, &centroids[b])))
+                .unwrap_or(0);
+            if assignments[i] != best {
+                assignments[i] = best;
+                changed = true;
+            }
+        }
+        for (c, centre) in centroids.iter_mut().enumerate() {
+            let members = points
+                .iter()
+                .zip(&assignments)
+                .filter(|(_, a)| **a == c)
+                .map(|(p, _)| p);
+            let mut members = members.peekable();
+            if members.peek().is_some() {
+                *centre = centroid(members);
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    Clusters {
+        centroids,
+        assignments,
+    }
+}
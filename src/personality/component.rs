@@ -0,0 +1,70 @@
+use bevy_ecs::prelude::*;
+
+/// Number of trait dimensions carried by a `Personality`.
+pub const TRAIT_COUNT: usize = 6;
+
+/// Names one dimension of a `Personality`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize)]
+pub enum Trait {
+    Openness,
+    Conscientiousness,
+    Extraversion,
+    Agreeableness,
+    Neuroticism,
+    Intelligence,
+}
+
+impl Trait {
+    pub const ALL: [Trait; TRAIT_COUNT] = [
+        Trait::Openness,
+        Trait::Conscientiousness,
+        Trait::Extraversion,
+        Trait::Agreeableness,
+        Trait::Neuroticism,
+        Trait::Intelligence,
+    ];
+
+    /// Position of this trait in `Personality::as_array`.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Personality {
+    pub openness: f64,
+    pub conscientiousness: f64,
+    pub extraversion: f64,
+    pub agreeableness: f64,
+    pub neuroticism: f64,
+    pub intelligence: f64,
+}
+
+impl Personality {
+    pub fn as_array(&self) -> [f64; TRAIT_COUNT] {
+        [
+            self.openness,
+            self.conscientiousness,
+            self.extraversion,
+            self.agreeableness,
+            self.neuroticism,
+            self.intelligence,
+        ]
+    }
+
+    pub fn get(&self, t: Trait) -> f64 {
+        self.as_array()[t.index()]
+    }
+
+    /// Inverse of `as_array`; traits are read in the same order.
+    pub fn from_array(a: [f64; TRAIT_COUNT]) -> Self {
+        Self {
+            openness: a[0],
+            conscientiousness: a[1],
+            extraversion: a[2],
+            agreeableness: a[3],
+            neuroticism: a[4],
+            intelligence: a[5],
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use bevy_ecs::prelude::*;
+
+use crate::personality::component::TRAIT_COUNT;
+use crate::personality::events::LifeEventKind;
+
+/// Population distribution that new personalities are drawn from.
+///
+/// Traits are sampled from a multivariate normal with per-trait `means` and
+/// `std_devs`, correlated by `correlation`, then clamped to `[min, max]`.
+/// Children of known parents instead regress toward the mid-parent value by
+/// `heritability` (narrow-sense, h²) plus correlated noise.
+/// Trait order follows `Personality::as_array`.
+#[derive(Resource, Clone, Debug)]
+pub struct PersonalityConfig {
+    pub means: [f64; TRAIT_COUNT],
+    pub std_devs: [f64; TRAIT_COUNT],
+    /// Symmetric, positive-definite, ones on the diagonal.
+    pub correlation: [[f64; TRAIT_COUNT]; TRAIT_COUNT],
+    pub min: f64,
+    pub max: f64,
+    pub heritability: [f64; TRAIT_COUNT],
+    /// Scales the inherited noise; 1.0 keeps population variance stable under random mating.
+    pub inheritance_noise: f64,
+}
+
+impl Default for PersonalityConfig {
+    fn default() -> Self {
+        // O, C, E, A, N, intelligence — rough inter-trait correlations from the Big Five literature.
+        Self {
+            means: [0.5; TRAIT_COUNT],
+            std_devs: [0.15; TRAIT_COUNT],
+            correlation: [
+                [1.00, 0.00, 0.25, 0.00, 0.00, 0.30],
+                [0.00, 1.00, 0.15, 0.25, -0.35, 0.00],
+                [0.25, 0.15, 1.00, 0.15, -0.25, 0.00],
+                [0.00, 0.25, 0.15, 1.00, -0.30, 0.00],
+                [0.00, -0.35, -0.25, -0.30, 1.00, 0.00],
+                [0.30, 0.00, 0.00, 0.00, 0.00, 1.00],
+            ],
+            min: 0.0,
+            max: 1.0,
+            // twin-study estimates, roughly 0.4–0.5 for the Big Five
+            heritability: [0.45, 0.40, 0.50, 0.40, 0.45, 0.50],
+            inheritance_noise: 1.0,
+        }
+    }
+}
+
+impl PersonalityConfig {
+    /// Lower-triangular Cholesky factor of the covariance matrix, or `None`
+    /// if the configured correlation matrix is not positive-definite.
+    pub fn covariance_factor(&self) -> Option<CovarianceFactor> {
+        let cov = std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.correlation[i][j] * self.std_devs[i] * self.std_devs[j])
+        });
+        cholesky(&cov).map(CovarianceFactor)
+    }
+}
+
+/// Cholesky factor of `PersonalityConfig`'s covariance. `PersonalityPlugin`
+/// validates the config and derives this once, and again whenever the
+/// config changes, instead of on every sample.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct CovarianceFactor(pub [[f64; TRAIT_COUNT]; TRAIT_COUNT]);
+
+impl CovarianceFactor {
+    /// Factor for independent traits with the given spreads, i.e. an
+    /// identity correlation matrix.
+    pub fn uncorrelated(std_devs: &[f64; TRAIT_COUNT]) -> Self {
+        Self(std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { std_devs[i] } else { 0.0 })
+        }))
+    }
+}
+
+fn cholesky(m: &[[f64; TRAIT_COUNT]; TRAIT_COUNT]) -> Option<[[f64; TRAIT_COUNT]; TRAIT_COUNT]> {
+    let mut l = [[0.0; TRAIT_COUNT]; TRAIT_COUNT];
+    for i in 0..TRAIT_COUNT {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| l[i][k] * l[j][k]).sum();
+            if i == j {
+                let d = m[i][i] - sum;
+                if d <= 0.0 {
+                    return None;
+                }
+                l[i][j] = d.sqrt();
+            } else {
+                l[i][j] = (m[i][j] - sum) / l[j][j];
+            }
+        }
+    }
+    Some(l)
+}
+
+/// Piecewise-linear drift rate (trait units per simulated year) over age.
+/// Held constant before the first and after the last point.
+#[derive(Clone, Debug, Default)]
+pub struct AgeCurve(pub Vec<(f32, f64)>);
+
+impl AgeCurve {
+    pub fn rate_at(&self, age: f32) -> f64 {
+        let pts = &self.0;
+        let (Some(first), Some(last)) = (pts.first(), pts.last()) else {
+            return 0.0;
+        };
+        if age <= first.0 {
+            return first.1;
+        }
+        if age >= last.0 {
+            return last.1;
+        }
+        for w in pts.windows(2) {
+            let ((a0, r0), (a1, r1)) = (w[0], w[1]);
+            if age <= a1 {
+                let t = ((age - a0) / (a1 - a0)) as f64;
+                return r0 + t * (r1 - r0);
+            }
+        }
+        last.1
+    }
+}
+
+/// How personalities change over a lifetime: slow age-driven drift, a small
+/// random walk, and one-off nudges when a `LifeEvent` happens.
+#[derive(Resource, Clone, Debug)]
+pub struct DevelopmentConfig {
+    /// One curve per trait, in `Personality::as_array` order.
+    pub curves: [AgeCurve; TRAIT_COUNT],
+    /// Random-walk standard deviation per square-root simulated year.
+    pub volatility: f64,
+    pub job_loss: [f64; TRAIT_COUNT],
+    pub bereavement: [f64; TRAIT_COUNT],
+    pub marriage: [f64; TRAIT_COUNT],
+}
+
+impl Default for DevelopmentConfig {
+    fn default() -> Self {
+        // Maturity principle: C and A rise through adulthood, N falls, O and
+        // intelligence ease off in old age.
+        Self {
+            curves: [
+                AgeCurve(vec![(20.0, 0.001), (60.0, 0.0), (80.0, -0.002)]),
+                AgeCurve(vec![(12.0, 0.0), (20.0, 0.005), (40.0, 0.003), (65.0, 0.001)]),
+                AgeCurve(vec![(20.0, 0.0), (60.0, -0.001)]),
+                AgeCurve(vec![(12.0, 0.0), (20.0, 0.002), (50.0, 0.003), (70.0, 0.002)]),
+                AgeCurve(vec![(12.0, 0.0), (20.0, -0.004), (50.0, -0.002), (70.0, 0.0)]),
+                AgeCurve(vec![(65.0, 0.0), (85.0, -0.003)]),
+            ],
+            volatility: 0.01,
+            job_loss: [0.0, -0.02, -0.01, 0.0, 0.03, 0.0],
+            bereavement: [0.0, 0.0, -0.02, 0.0, 0.04, 0.0],
+            marriage: [0.0, 0.01, 0.0, 0.01, -0.02, 0.0],
+        }
+    }
+}
+
+impl DevelopmentConfig {
+    pub fn nudge(&self, kind: LifeEventKind) -> [f64; TRAIT_COUNT] {
+        match kind {
+            LifeEventKind::JobLoss => self.job_loss,
+            LifeEventKind::Bereavement => self.bereavement,
+            LifeEventKind::Marriage => self.marriage,
+        }
+    }
+}
+
+/// How often and how finely `Records::archetypes` is recomputed.
+#[derive(Resource, Clone, Debug)]
+pub struct ArchetypeConfig {
+    pub k: usize,
+    pub iterations: usize,
+    pub interval_secs: f64,
+    /// Clustering uses its own RNG so recording never perturbs `GameRNG`.
+    pub seed: u64,
+}
+
+impl Default for ArchetypeConfig {
+    fn default() -> Self {
+        Self {
+            k: 4,
+            iterations: 20,
+            interval_secs: 30.0,
+            seed: 0,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LifeEventKind {
+    JobLoss,
+    Bereavement,
+    /// Nothing sends this yet; `DevelopmentConfig::marriage` is ready for
+    /// when people marry.
+    Marriage,
+}
+
+/// Something happened to `entity` that shifts its personality.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LifeEvent {
+    pub entity: Entity,
+    pub kind: LifeEventKind,
+}
@@ -0,0 +1,15 @@
+pub mod analytics;
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::{Personality, Trait, TRAIT_COUNT};
+pub use analytics::{kmeans, nearest_neighbours, Archetype, Clusters, Metric};
+pub use config::{
+    AgeCurve, ArchetypeConfig, CovarianceFactor, DevelopmentConfig, PersonalityConfig,
+};
+pub use events::{LifeEvent, LifeEventKind};
+pub use plugin::{AssignPersonalities, PersonalityPlugin};
+pub use system::{inherit_personality, sample_personality};
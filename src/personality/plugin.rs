@@ -0,0 +1,60 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::baby_spawner::system::{spawn_babies, GameRNG};
+use crate::mortality::system::despawn_on_death;
+use crate::mortality::Death;
+use crate::person::AgingConfig;
+use crate::personality::config::{
+    ArchetypeConfig, CovarianceFactor, DevelopmentConfig, PersonalityConfig,
+};
+use crate::personality::events::LifeEvent;
+use crate::personality::system::{
+    apply_life_events, assign_personalities, develop_personalities, emit_bereavement,
+    refresh_covariance_factor,
+};
+
+pub struct PersonalityPlugin;
+
+/// Systems that give new persons their `Personality`. Order readers of
+/// newborn traits after this set; the traits arrive via commands.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssignPersonalities;
+
+impl Plugin for PersonalityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PersonalityConfig>();
+        // Checked here so a bad config is reported at startup rather than on
+        // the first sample; later edits are picked up by refresh_covariance_factor.
+        let cfg = app.world().resource::<PersonalityConfig>();
+        let factor = cfg.covariance_factor().unwrap_or_else(|| {
+            bevy_log::error!(
+                "PersonalityConfig.correlation is not positive-definite; sampling traits independently"
+            );
+            CovarianceFactor::uncorrelated(&cfg.std_devs)
+        });
+        app.insert_resource(factor)
+            .init_resource::<DevelopmentConfig>()
+            .init_resource::<ArchetypeConfig>()
+            .init_resource::<AgingConfig>()
+            .init_resource::<GameRNG>()
+            .add_event::<Death>()
+            .add_event::<LifeEvent>()
+            .add_systems(
+                Update,
+                (refresh_covariance_factor, assign_personalities)
+                    .chain()
+                    .in_set(AssignPersonalities)
+                    .after(spawn_babies),
+            )
+            .add_systems(
+                Update,
+                (
+                    develop_personalities,
+                    emit_bereavement.before(despawn_on_death),
+                    apply_life_events,
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,160 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::mortality::Death;
+use crate::person::{AgingConfig, Parents, Person};
+use crate::personality::component::{Personality, TRAIT_COUNT};
+use crate::personality::config::{CovarianceFactor, DevelopmentConfig, PersonalityConfig};
+use crate::personality::events::{LifeEvent, LifeEventKind};
+
+/// Correlated zero-mean deviation with the population covariance.
+fn correlated_noise<R: Rng + ?Sized>(l: &CovarianceFactor, rng: &mut R) -> [f64; TRAIT_COUNT] {
+    let z: [f64; TRAIT_COUNT] = std::array::from_fn(|_| rng.sample(StandardNormal));
+    std::array::from_fn(|i| (0..=i).map(|k| l.0[i][k] * z[k]).sum())
+}
+
+/// Draw one personality from the configured population distribution;
+/// `l` is `cfg.covariance_factor()`.
+pub fn sample_personality<R: Rng + ?Sized>(
+    cfg: &PersonalityConfig,
+    l: &CovarianceFactor,
+    rng: &mut R,
+) -> Personality {
+    let noise = correlated_noise(l, rng);
+    Personality::from_array(std::array::from_fn(|i| {
+        (cfg.means[i] + noise[i]).clamp(cfg.min, cfg.max)
+    }))
+}
+
+/// Child traits: population mean + h² · (mid-parent − mean) + noise.
+/// Noise variance is σ²(1 − h⁴/2), which keeps the population variance
+/// steady across generations when mates are picked at random.
+pub fn inherit_personality<R: Rng + ?Sized>(
+    cfg: &PersonalityConfig,
+    l: &CovarianceFactor,
+    a: &Personality,
+    b: &Personality,
+    rng: &mut R,
+) -> Personality {
+    let (a, b) = (a.as_array(), b.as_array());
+    let noise = correlated_noise(l, rng);
+    Personality::from_array(std::array::from_fn(|i| {
+        let h2 = cfg.heritability[i];
+        let mid = 0.5 * (a[i] + b[i]);
+        let spread = cfg.inheritance_noise * (1.0 - 0.5 * h2 * h2).max(0.0).sqrt();
+        (cfg.means[i] + h2 * (mid - cfg.means[i]) + spread * noise[i]).clamp(cfg.min, cfg.max)
+    }))
+}
+
+/// Give every person that lacks one a `Personality`: inherited when both
+/// parents have one, otherwise drawn from the population distribution.
+/// Covers newborns as well as agents seeded directly into the world.
+#[allow(clippy::type_complexity)]
+pub fn assign_personalities(
+    mut commands: Commands,
+    cfg: Res<PersonalityConfig>,
+    factor: Res<CovarianceFactor>,
+    mut rng: ResMut<GameRNG>,
+    people: Query<(Entity, Option<&Parents>), (With<Person>, Without<Personality>)>,
+    known: Query<&Personality>,
+) {
+    for (e, parents) in people.iter() {
+        let inherited = parents.and_then(|p| Some((known.get(p.0).ok()?, known.get(p.1).ok()?)));
+        let p = match inherited {
+            Some((a, b)) => inherit_personality(&cfg, &factor, a, b, &mut rng.0),
+            None => sample_personality(&cfg, &factor, &mut rng.0),
+        };
+        commands.entity(e).insert(p);
+    }
+}
+
+/// Re-derive `CovarianceFactor` after `PersonalityConfig` changes. An
+/// invalid config is logged and the previous factor kept.
+pub fn refresh_covariance_factor(
+    cfg: Res<PersonalityConfig>,
+    mut factor: ResMut<CovarianceFactor>,
+) {
+    if !cfg.is_changed() {
+        return;
+    }
+    match cfg.covariance_factor() {
+        Some(l) => *factor = l,
+        None => bevy_log::error!(
+            "PersonalityConfig.correlation is not positive-definite; keeping the previous covariance"
+        ),
+    }
+}
+
+/// Drift every personality along its age curves, plus a small random walk.
+pub fn develop_personalities(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    dev: Res<DevelopmentConfig>,
+    bounds: Res<PersonalityConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut people: Query<(&Person, &mut Personality)>,
+) {
+    let dy = time.delta_secs_f64() * aging.years_per_sec;
+    if dy <= 0.0 {
+        return;
+    }
+    let spread = dev.volatility * dy.sqrt();
+    for (person, mut p) in people.iter_mut() {
+        let traits = p.as_array();
+        *p = Personality::from_array(std::array::from_fn(|i| {
+            let mut t = traits[i] + dev.curves[i].rate_at(person.age) * dy;
+            if spread > 0.0 {
+                t += spread * rng.0.sample::<f64, _>(StandardNormal);
+            }
+            t.clamp(bounds.min, bounds.max)
+        }));
+    }
+}
+
+/// Apply the configured one-off nudge for each `LifeEvent`.
+pub fn apply_life_events(
+    dev: Res<DevelopmentConfig>,
+    bounds: Res<PersonalityConfig>,
+    mut events: EventReader<LifeEvent>,
+    mut people: Query<&mut Personality>,
+) {
+    for ev in events.read() {
+        if let Ok(mut p) = people.get_mut(ev.entity) {
+            let nudge = dev.nudge(ev.kind);
+            let traits = p.as_array();
+            *p = Personality::from_array(std::array::from_fn(|i| {
+                (traits[i] + nudge[i]).clamp(bounds.min, bounds.max)
+            }));
+        }
+    }
+}
+
+/// Children and parents of the deceased are bereaved. Parents are only found
+/// while the deceased still exists, i.e. when read in the frame of death.
+pub fn emit_bereavement(
+    mut deaths: EventReader<Death>,
+    lineage: Query<(Entity, &Parents)>,
+    mut writer: EventWriter<LifeEvent>,
+) {
+    for d in deaths.read() {
+        for (child, parents) in lineage.iter() {
+            if parents.contains(d.entity) {
+                writer.write(LifeEvent {
+                    entity: child,
+                    kind: LifeEventKind::Bereavement,
+                });
+            }
+        }
+        if let Ok((_, &Parents(a, b))) = lineage.get(d.entity) {
+            for entity in [a, b] {
+                writer.write(LifeEvent {
+                    entity,
+                    kind: LifeEventKind::Bereavement,
+                });
+            }
+        }
+    }
+}
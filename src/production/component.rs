@@ -0,0 +1,6 @@
+use bevy_ecs::prelude::*;
+use std::collections::HashMap;
+
+/// Fractions of a unit made so far, by `(role_index, recipe index)`.
+#[derive(Component, Debug, Clone, Default)]
+pub struct WorkInProgress(pub HashMap<(usize, usize), f64>);
@@ -0,0 +1,22 @@
+use bevy_ecs::prelude::*;
+
+/// A member's productivity is 1 at conscientiousness 0.5 and, in roles that
+/// train skills, a mean trained skill of 0.5; each gain scales the change
+/// per unit away from that. A job stops producing while its `Inventory`
+/// holds `inventory_capacity` items, so unsold goods stay bounded.
+#[derive(Resource, Clone, Debug)]
+pub struct ProductionConfig {
+    pub conscientiousness_gain: f64,
+    pub skill_gain: f64,
+    pub inventory_capacity: usize,
+}
+
+impl Default for ProductionConfig {
+    fn default() -> Self {
+        Self {
+            conscientiousness_gain: 1.0,
+            skill_gain: 1.0,
+            inventory_capacity: 1000,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use bevy_ecs::prelude::*;
+
+/// Role `role_index` of `job` made `count` items of `kind` this frame.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct Produced {
+    pub job: Entity,
+    pub role_index: usize,
+    pub kind: String,
+    pub count: u32,
+}
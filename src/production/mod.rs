@@ -0,0 +1,11 @@
+pub mod component;
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use component::WorkInProgress;
+pub use config::ProductionConfig;
+pub use events::Produced;
+pub use plugin::ProductionPlugin;
+pub use system::{productivity, run_production};
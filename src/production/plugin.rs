@@ -0,0 +1,20 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::person::AgingConfig;
+use crate::production::config::ProductionConfig;
+use crate::production::events::Produced;
+use crate::production::system::run_production;
+
+/// Work makes goods: roles with recipes turn their attending members'
+/// time into `Item`s in the job's `Inventory`, using up inputs held there.
+pub struct ProductionPlugin;
+
+impl Plugin for ProductionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProductionConfig>()
+            .init_resource::<AgingConfig>()
+            .add_event::<Produced>()
+            .add_systems(Update, run_production);
+    }
+}
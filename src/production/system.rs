@@ -0,0 +1,145 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::{HashMap, HashSet};
+
+use crate::inventory::component::{Inventory, Item};
+use crate::jobs::{Absent, Job, RoleSpec};
+use crate::person::{AgingConfig, Skills};
+use crate::personality::Personality;
+use crate::production::component::WorkInProgress;
+use crate::production::config::ProductionConfig;
+use crate::production::events::Produced;
+
+/// Output of one member of `spec` relative to an average one, never
+/// negative: conscientious and, where the role trains skills, skilled
+/// members make more.
+pub fn productivity(
+    cfg: &ProductionConfig,
+    spec: &RoleSpec,
+    p: Option<&Personality>,
+    skills: Option<&Skills>,
+) -> f64 {
+    let c = p.map_or(0.5, |p| p.conscientiousness);
+    let mut x = 1.0 + cfg.conscientiousness_gain * (c - 0.5);
+    if !spec.trains.is_empty() {
+        x *= 1.0 + cfg.skill_gain * (spec.skill_match(skills) - 0.5);
+    }
+    x.max(0.0)
+}
+
+/// Advance every recipe by its rate times the attending members' summed
+/// productivity, and turn each whole unit into an `Item` in the job's
+/// `Inventory`. A unit whose inputs are not all in stock, or that would
+/// take the inventory past `inventory_capacity`, waits.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn run_production(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<ProductionConfig>,
+    mut jobs: Query<(
+        Entity,
+        &Job,
+        Option<&mut Inventory>,
+        Option<&mut WorkInProgress>,
+    )>,
+    people: Query<(Option<&Personality>, Option<&Skills>), Without<Absent>>,
+    items: Query<&Item>,
+    mut produced: EventWriter<Produced>,
+) {
+    let dt = time.delta_secs_f64() * aging.years_per_sec;
+    if dt <= 0.0 {
+        return;
+    }
+    for (e, job, inventory, progress) in jobs.iter_mut() {
+        if job.roles.iter().all(|(spec, _)| spec.produces.is_empty()) {
+            continue;
+        }
+        let mut fresh = WorkInProgress::default();
+        let had_progress = progress.is_some();
+        let progress = match progress {
+            Some(p) => p.into_inner(),
+            None => &mut fresh,
+        };
+        let held: &[Entity] = inventory.as_ref().map_or(&[], |inv| inv.items.as_slice());
+        let mut stock: HashMap<&str, Vec<Entity>> = HashMap::new();
+        for &item in held.iter().rev() {
+            if let Ok(it) = items.get(item) {
+                stock.entry(it.kind.as_str()).or_default().push(item);
+            }
+        }
+
+        let mut used: HashSet<Entity> = HashSet::new();
+        let mut made: Vec<Entity> = Vec::new();
+        for (i, (spec, members)) in job.roles.iter().enumerate() {
+            if spec.produces.is_empty() {
+                continue;
+            }
+            let effort: f64 = members
+                .iter()
+                .filter_map(|&m| people.get(m).ok())
+                .map(|(p, skills)| productivity(&cfg, spec, p, skills))
+                .sum::<f64>()
+                / spec.produces.len() as f64;
+            for (k, recipe) in spec.produces.iter().enumerate() {
+                let wip = progress.0.entry((i, k)).or_default();
+                *wip += recipe.rate.max(0.0) as f64 * effort * dt;
+                let mut count = 0;
+                while *wip >= 1.0 {
+                    let in_stock = recipe.inputs.iter().all(|(kind, n)| {
+                        stock.get(kind.as_str()).map_or(0, Vec::len) >= *n as usize
+                    });
+                    let full = held.len() - used.len() + made.len() >= cfg.inventory_capacity;
+                    if !in_stock || full {
+                        *wip = wip.min(1.0);
+                        break;
+                    }
+                    for (kind, n) in &recipe.inputs {
+                        if let Some(pile) = stock.get_mut(kind.as_str()) {
+                            for _ in 0..*n {
+                                used.extend(pile.pop());
+                            }
+                        }
+                    }
+                    made.push(
+                        commands
+                            .spawn(Item {
+                                kind: recipe.output.clone(),
+                            })
+                            .id(),
+                    );
+                    *wip -= 1.0;
+                    count += 1;
+                }
+                if count > 0 {
+                    produced.write(Produced {
+                        job: e,
+                        role_index: i,
+                        kind: recipe.output.clone(),
+                        count,
+                    });
+                }
+            }
+        }
+
+        for &item in &used {
+            commands.entity(item).despawn();
+        }
+        match inventory {
+            Some(mut inv) => {
+                if !used.is_empty() {
+                    inv.items.retain(|item| !used.contains(item));
+                }
+                if !made.is_empty() {
+                    inv.items.extend(made);
+                }
+            }
+            None => {
+                commands.entity(e).insert(Inventory { items: made });
+            }
+        }
+        if !had_progress {
+            commands.entity(e).insert(fresh);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use crate::personality::TRAIT_COUNT;
+
+/// Running per-trait mean and variance (Welford's algorithm).
+#[derive(Debug, Clone, Default)]
+pub struct TraitMoments {
+    pub count: usize,
+    mean: [f64; TRAIT_COUNT],
+    m2: [f64; TRAIT_COUNT],
+}
+
+impl TraitMoments {
+    pub fn push(&mut self, traits: [f64; TRAIT_COUNT]) {
+        self.count += 1;
+        let n = self.count as f64;
+        for (i, x) in traits.into_iter().enumerate() {
+            let delta = x - self.mean[i];
+            self.mean[i] += delta / n;
+            self.m2[i] += delta * (x - self.mean[i]);
+        }
+    }
+
+    pub fn mean(&self) -> [f64; TRAIT_COUNT] {
+        self.mean
+    }
+
+    /// Population variance; zero until two samples have been seen.
+    pub fn variance(&self) -> [f64; TRAIT_COUNT] {
+        if self.count < 2 {
+            return [0.0; TRAIT_COUNT];
+        }
+        self.m2.map(|m2| m2 / self.count as f64)
+    }
+}
+
+/// Trait moments bucketed by birth cohort of fixed length.
+#[derive(Debug, Clone)]
+pub struct CohortStats {
+    /// Cohort length in the same seconds as `Time<Real>`.
+    pub window: f64,
+    cohorts: BTreeMap<u64, TraitMoments>,
+}
+
+impl CohortStats {
+    pub fn new(window: f64) -> Self {
+        Self {
+            window,
+            cohorts: BTreeMap::new(),
+        }
+    }
+
+    /// Index of the cohort that contains time `t`.
+    pub fn cohort_of(&self, t: f64) -> u64 {
+        (t.max(0.0) / self.window) as u64
+    }
+
+    /// Record one birth at `t` seconds with the newborn's traits.
+    pub fn push(&mut self, t: f64, traits: [f64; TRAIT_COUNT]) {
+        let c = self.cohort_of(t);
+        self.cohorts.entry(c).or_default().push(traits);
+    }
+
+    pub fn get(&self, cohort: u64) -> Option<&TraitMoments> {
+        self.cohorts.get(&cohort)
+    }
+
+    /// Cohorts in chronological order.
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &TraitMoments)> {
+        self.cohorts.iter().map(|(&c, m)| (c, m))
+    }
+}
@@ -0,0 +1,87 @@
+use std::collections::VecDeque;
+
+use crate::records::RollingMean;
+
+/// Samples kept in `EpidemicStats::history`.
+pub const EPIDEMIC_HISTORY_MAX: usize = 1024;
+
+/// One point of the epidemic time series.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpidemicSample {
+    /// `Time<Real>` seconds.
+    pub t: f64,
+    /// New infections within the window ending at `t`.
+    pub incidence: usize,
+    pub prevalence: f32,
+    pub r_effective: f64,
+}
+
+/// Incidence, prevalence and R-effective over a sliding window, sampled
+/// into `history` once per window. When `history` is full every other
+/// sample is dropped, so older windows are kept at coarser resolution.
+#[derive(Debug, Clone)]
+pub struct EpidemicStats {
+    /// Window length in the same seconds as `Time<Real>`.
+    pub window: f64,
+    pub incidence: RollingMean,
+    /// Share of people currently infectious.
+    pub prevalence: f32,
+    /// Mean secondary cases of infections that ended within the window.
+    pub r_effective: f64,
+    pub history: Vec<EpidemicSample>,
+    ended: VecDeque<(f64, u32)>,
+    last_sample: Option<f64>,
+}
+
+impl EpidemicStats {
+    pub fn new(window: f64) -> Self {
+        Self {
+            window,
+            incidence: RollingMean::new(window),
+            prevalence: 0.0,
+            r_effective: 0.0,
+            history: Vec::new(),
+            ended: VecDeque::new(),
+            last_sample: None,
+        }
+    }
+
+    pub fn push_infection(&mut self, t: f64) {
+        self.incidence.push(t);
+    }
+
+    pub fn push_ended(&mut self, t: f64, secondary: u32) {
+        self.ended.push_back((t, secondary));
+    }
+
+    /// Drop events older than the window, refresh R-effective, and append
+    /// to `history` if a window has passed since the last sample.
+    pub fn update(&mut self, now: f64, prevalence: f32) {
+        self.incidence.prune(now);
+        while self.ended.front().is_some_and(|&(t, _)| t < now - self.window) {
+            self.ended.pop_front();
+        }
+        self.prevalence = prevalence;
+        self.r_effective = if self.ended.is_empty() {
+            0.0
+        } else {
+            self.ended.iter().map(|&(_, n)| n as f64).sum::<f64>() / self.ended.len() as f64
+        };
+        if self.last_sample.is_none_or(|t| now - t >= self.window) {
+            self.last_sample = Some(now);
+            if self.history.len() >= EPIDEMIC_HISTORY_MAX {
+                let mut i = 0;
+                self.history.retain(|_| {
+                    i += 1;
+                    i % 2 == 1
+                });
+            }
+            self.history.push(EpidemicSample {
+                t: now,
+                incidence: self.incidence.count(),
+                prevalence: self.prevalence,
+                r_effective: self.r_effective,
+            });
+        }
+    }
+}
@@ -1,10 +1,19 @@
+pub mod cohort;
+pub mod epidemic;
 pub mod plugin;
+#[allow(clippy::module_inception)]
 pub mod records;
 pub mod rolling_mean;
 #[cfg(feature = "graphics")]
 pub mod ui;
 
-pub use self::records::{record_births, record_deaths, record_employment_rate, Records};
+pub use self::cohort::{CohortStats, TraitMoments};
+pub use self::epidemic::{EpidemicSample, EpidemicStats, EPIDEMIC_HISTORY_MAX};
+pub use self::records::{
+    record_archetypes, record_births, record_deaths, record_diffusion, record_employment_rate,
+    record_epidemic, record_job_fit, record_payroll, record_referral_share, record_trait_cohorts,
+    Records, ADOPTION_CURVE_MAX,
+};
 pub use self::rolling_mean::RollingMean;
 #[cfg(feature = "graphics")]
 pub use self::ui::{
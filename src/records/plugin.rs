@@ -4,11 +4,21 @@ use crate::records::ui::{
     VacancyTextPlugin,
 };
 use crate::records::Records;
-use crate::records::{record_births, record_deaths, record_employment_rate};
+use crate::personality::{ArchetypeConfig, AssignPersonalities};
+use crate::diffusion::DiffusionStep;
+use crate::economy::Payday;
+use crate::epidemic::Infected;
+use crate::records::{
+    record_archetypes, record_births, record_deaths, record_diffusion, record_employment_rate,
+    record_epidemic, record_job_fit, record_payroll, record_referral_share, record_trait_cohorts,
+};
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::prelude::*;
 
+/// Records what the installed plugins report. Statistics of subsystems
+/// that are not installed (diffusion, epidemic, payroll, archetypes) are
+/// skipped; their plugins register the events and config read here.
 pub struct RecordsPlugin;
 
 impl Plugin for RecordsPlugin {
@@ -20,7 +30,18 @@ impl Plugin for RecordsPlugin {
 
         app.add_systems(
             Update,
-            (record_births, record_deaths, record_employment_rate),
+            (
+                record_births,
+                record_deaths,
+                record_employment_rate,
+                record_job_fit,
+                record_referral_share,
+                record_diffusion.run_if(resource_exists::<Events<DiffusionStep>>),
+                record_epidemic.run_if(resource_exists::<Events<Infected>>),
+                record_payroll.run_if(resource_exists::<Events<Payday>>),
+                record_trait_cohorts.after(AssignPersonalities),
+                record_archetypes.run_if(resource_exists::<ArchetypeConfig>),
+            ),
         );
     }
 }
@@ -1,11 +1,21 @@
 use crate::baby_spawner::BabyBorn;
+use crate::diffusion::DiffusionStep;
+use crate::economy::Payday;
+use crate::epidemic::{Infected, Infection, InfectionEnded, Stage};
 use crate::hiring_manager::component::Unemployed;
+use crate::jobs::Employment;
 use crate::mortality::Death;
 use crate::person::Person;
-use crate::records::RollingMean;
+use crate::personality::{kmeans, Archetype, ArchetypeConfig, Personality};
+use crate::records::{CohortStats, EpidemicStats, RollingMean};
 use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 use bevy_time::{Real, Time};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Points kept in `Records::adoption_curve`.
+pub const ADOPTION_CURVE_MAX: usize = 1024;
 
 #[derive(Resource, Debug, Clone)]
 pub struct Records {
@@ -14,6 +24,47 @@ pub struct Records {
     pub birth_rate: RollingMean,
     pub death_rate: RollingMean,
     pub employment_rate: f32,
+    /// Newborn trait means/variances keyed by birth cohort.
+    pub trait_cohorts: CohortStats,
+    /// Latest k-means personality archetypes, largest first.
+    pub archetypes: Vec<Archetype>,
+    /// Mean personality fit of currently employed people whose role has a profile.
+    pub mean_job_fit: f64,
+    /// Share of currently employed people who were hired through a referral.
+    pub referral_share: f32,
+    /// (`Time<Real>` seconds, share adopted) after each diffusion round, at
+    /// most `ADOPTION_CURVE_MAX` points; see `record_diffusion`.
+    pub adoption_curve: Vec<(f64, f32)>,
+    /// Mean DeGroot opinion after the latest round.
+    pub mean_opinion: f64,
+    pub epidemic: EpidemicStats,
+    /// Yearly income of people paid on the latest payday, at the rate they were paid.
+    pub mean_income: f64,
+    pub median_income: f64,
+    /// Wages paid out on the latest payday.
+    pub total_payroll: f64,
+}
+
+impl Default for Records {
+    fn default() -> Self {
+        Self {
+            births: 0,
+            deaths: 0,
+            birth_rate: RollingMean::new(1.0),
+            death_rate: RollingMean::new(1.0),
+            employment_rate: 0.0,
+            trait_cohorts: CohortStats::new(1.0),
+            archetypes: Vec::new(),
+            mean_job_fit: 0.0,
+            referral_share: 0.0,
+            adoption_curve: Vec::new(),
+            mean_opinion: 0.0,
+            epidemic: EpidemicStats::new(1.0),
+            mean_income: 0.0,
+            median_income: 0.0,
+            total_payroll: 0.0,
+        }
+    }
 }
 
 impl Records {
@@ -46,6 +97,20 @@ pub fn record_deaths(
     }
 }
 
+pub fn record_trait_cohorts(
+    time: Res<Time<Real>>,
+    mut records: ResMut<Records>,
+    mut born: EventReader<BabyBorn>,
+    traits: Query<&Personality>,
+) {
+    let now = time.elapsed_secs_f64();
+    for ev in born.read() {
+        if let Ok(p) = traits.get(ev.entity) {
+            records.trait_cohorts.push(now, p.as_array());
+        }
+    }
+}
+
 pub fn record_employment_rate(
     mut records: ResMut<Records>,
     people: Query<Entity, With<Person>>,
@@ -60,3 +125,123 @@ pub fn record_employment_rate(
         records.employment_rate = 0.0;
     }
 }
+
+pub fn record_job_fit(mut records: ResMut<Records>, matches: Query<&Employment>) {
+    let fits: Vec<f64> = matches.iter().filter_map(|m| m.fit).collect();
+    records.mean_job_fit = if fits.is_empty() {
+        0.0
+    } else {
+        fits.iter().sum::<f64>() / fits.len() as f64
+    };
+}
+
+pub fn record_referral_share(mut records: ResMut<Records>, matches: Query<&Employment>) {
+    let total = matches.iter().count();
+    let referred = matches.iter().filter(|m| m.referred_by.is_some()).count();
+    records.referral_share = if total > 0 {
+        referred as f32 / total as f32
+    } else {
+        0.0
+    };
+}
+
+/// Appends to the adoption curve; when it is full every other point is
+/// dropped, so older rounds are kept at progressively coarser resolution.
+pub fn record_diffusion(
+    time: Res<Time<Real>>,
+    mut records: ResMut<Records>,
+    mut steps: EventReader<DiffusionStep>,
+) {
+    let now = time.elapsed_secs_f64();
+    for step in steps.read() {
+        let share = if step.population > 0 {
+            step.adopted as f32 / step.population as f32
+        } else {
+            0.0
+        };
+        if records.adoption_curve.len() >= ADOPTION_CURVE_MAX {
+            let mut i = 0;
+            records.adoption_curve.retain(|_| {
+                i += 1;
+                i % 2 == 1
+            });
+        }
+        records.adoption_curve.push((now, share));
+        if let Some(m) = step.mean_opinion {
+            records.mean_opinion = m;
+        }
+    }
+}
+
+pub fn record_epidemic(
+    time: Res<Time<Real>>,
+    mut records: ResMut<Records>,
+    mut infected: EventReader<Infected>,
+    mut ended: EventReader<InfectionEnded>,
+    people: Query<(), With<Person>>,
+    cases: Query<&Infection>,
+) {
+    let now = time.elapsed_secs_f64();
+    for _ in infected.read() {
+        records.epidemic.push_infection(now);
+    }
+    for ev in ended.read() {
+        records.epidemic.push_ended(now, ev.secondary);
+    }
+    let total = people.iter().count();
+    let infectious = cases.iter().filter(|i| i.stage == Stage::Infectious).count();
+    let prevalence = if total > 0 {
+        infectious as f32 / total as f32
+    } else {
+        0.0
+    };
+    records.epidemic.update(now, prevalence);
+}
+
+pub fn record_payroll(mut records: ResMut<Records>, mut paydays: EventReader<Payday>) {
+    for payday in paydays.read() {
+        let mut incomes: Vec<f64> = payday
+            .payments
+            .iter()
+            .map(|&(_, a)| a / payday.period_years)
+            .collect();
+        incomes.sort_by(f64::total_cmp);
+        let n = incomes.len();
+        records.total_payroll = payday.total();
+        records.mean_income = if n > 0 {
+            incomes.iter().sum::<f64>() / n as f64
+        } else {
+            0.0
+        };
+        records.median_income = match n {
+            0 => 0.0,
+            _ if n % 2 == 1 => incomes[n / 2],
+            _ => (incomes[n / 2 - 1] + incomes[n / 2]) / 2.0,
+        };
+    }
+}
+
+/// Periodically cluster living personalities into archetypes. The n-th
+/// clustering is seeded with `cfg.seed + n`, so labels do not depend on
+/// frame timing.
+pub fn record_archetypes(
+    time: Res<Time<Real>>,
+    cfg: Res<ArchetypeConfig>,
+    mut records: ResMut<Records>,
+    mut last_run: Local<Option<f64>>,
+    mut runs: Local<u64>,
+    people: Query<(Entity, &Personality)>,
+) {
+    let now = time.elapsed_secs_f64();
+    if last_run.is_some_and(|t| now - t < cfg.interval_secs) {
+        return;
+    }
+    *last_run = Some(now);
+
+    let mut pool: Vec<_> = people.iter().map(|(e, p)| (e, p.as_array())).collect();
+    pool.sort_by_key(|(e, _)| *e);
+    let points: Vec<_> = pool.into_iter().map(|(_, p)| p).collect();
+    let mut rng = StdRng::seed_from_u64(cfg.seed.wrapping_add(*runs));
+    *runs += 1;
+    records.archetypes = kmeans(&points, cfg.k, cfg.iterations, &mut rng).archetypes();
+}
@@ -15,7 +15,7 @@ pub struct RollingMean {
 impl RollingMean {
     pub fn new(window: f64) -> Self {
         Self {
-            window: window,
+            window,
             timestamps: VecDeque::<f64>::new(),
         }
     }
@@ -0,0 +1,29 @@
+use bevy_ecs::prelude::*;
+
+/// Rates are per simulated year. A trait or satisfaction of 0.5 is neutral;
+/// each gain scales the log hazard per unit away from it.
+#[derive(Resource, Clone, Debug)]
+pub struct SeparationConfig {
+    /// Quit hazard of an average, averagely satisfied member.
+    pub quit_rate_per_year: f64,
+    pub neuroticism_gain: f64,
+    pub conscientiousness_gain: f64,
+    pub satisfaction_gain: f64,
+    /// Members performing below this risk dismissal...
+    pub performance_threshold: f64,
+    /// ...at up to this rate, reached at zero performance.
+    pub dismissal_rate_per_year: f64,
+}
+
+impl Default for SeparationConfig {
+    fn default() -> Self {
+        Self {
+            quit_rate_per_year: 0.1,
+            neuroticism_gain: 1.0,
+            conscientiousness_gain: 1.0,
+            satisfaction_gain: 2.0,
+            performance_threshold: 0.35,
+            dismissal_rate_per_year: 1.0,
+        }
+    }
+}
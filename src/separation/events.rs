@@ -0,0 +1,22 @@
+use bevy_ecs::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeparationReason {
+    /// The person left of their own accord.
+    Quit,
+    /// Let go for poor performance.
+    Dismissed,
+    /// The role lost the seat (it shrank or was removed).
+    LaidOff,
+    /// Left a student role on outgrowing it.
+    Graduated,
+}
+
+/// `person` stopped holding a seat in `role_index` of `job`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Separated {
+    pub person: Entity,
+    pub job: Entity,
+    pub role_index: usize,
+    pub reason: SeparationReason,
+}
@@ -0,0 +1,11 @@
+pub mod config;
+pub mod events;
+pub mod plugin;
+pub mod system;
+
+pub use config::SeparationConfig;
+pub use events::{Separated, SeparationReason};
+pub use plugin::SeparationPlugin;
+pub use system::{
+    dismissal_hazard, performance, quit_hazard, release, satisfaction, separate,
+};
@@ -0,0 +1,38 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::gregslist::component::VacancyDirty;
+use crate::jobs::system::reload_job_catalog;
+use crate::jobs::LayoffRequest;
+use crate::person::AgingConfig;
+use crate::personality::LifeEvent;
+use crate::separation::config::SeparationConfig;
+use crate::separation::events::Separated;
+use crate::separation::system::{lay_off_excess, lay_off_requested, quit_and_dismiss};
+
+/// Members leave jobs: they quit, are dismissed for poor performance, or
+/// are laid off when their role shrinks. Each separation restores
+/// `Unemployed`, marks the job's vacancies dirty and sends `Separated`.
+pub struct SeparationPlugin;
+
+impl Plugin for SeparationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeparationConfig>()
+            .init_resource::<AgingConfig>()
+            .init_resource::<GameRNG>()
+            .add_event::<VacancyDirty>()
+            .add_event::<Separated>()
+            .add_event::<LayoffRequest>()
+            .add_event::<LifeEvent>()
+            .add_systems(
+                Update,
+                (
+                    lay_off_requested.after(reload_job_catalog),
+                    lay_off_excess,
+                    quit_and_dismiss,
+                )
+                    .chain(),
+            );
+    }
+}
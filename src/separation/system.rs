@@ -0,0 +1,227 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::Rng;
+
+use crate::baby_spawner::system::GameRNG;
+use crate::gregslist::component::VacancyDirty;
+use crate::hiring_manager::component::Unemployed;
+use crate::jobs::{Employment, Job, LayoffRequest};
+use crate::person::AgingConfig;
+use crate::personality::{LifeEvent, LifeEventKind, Personality};
+use crate::separation::config::SeparationConfig;
+use crate::separation::events::{Separated, SeparationReason};
+
+/// How content a member is with their role, in `[0, 1]`: their personality
+/// fit, or 0.5 when the role has no profile.
+pub fn satisfaction(m: Option<&Employment>) -> f64 {
+    m.and_then(|m| m.fit).unwrap_or(0.5)
+}
+
+/// How well a member does their job, in `[0, 1]`: conscientiousness
+/// averaged with role fit.
+pub fn performance(p: Option<&Personality>, m: Option<&Employment>) -> f64 {
+    (p.map_or(0.5, |p| p.conscientiousness) + satisfaction(m)) / 2.0
+}
+
+/// Yearly quit rate: neurotic and dissatisfied people leave sooner,
+/// conscientious ones stay.
+pub fn quit_hazard(cfg: &SeparationConfig, p: Option<&Personality>, m: Option<&Employment>) -> f64 {
+    let (n, c) = p.map_or((0.5, 0.5), |p| (p.neuroticism, p.conscientiousness));
+    cfg.quit_rate_per_year
+        * (cfg.neuroticism_gain * (n - 0.5)
+            - cfg.conscientiousness_gain * (c - 0.5)
+            - cfg.satisfaction_gain * (satisfaction(m) - 0.5))
+            .exp()
+}
+
+/// Yearly dismissal rate: zero at or above the threshold, rising linearly
+/// to `dismissal_rate_per_year` at zero performance.
+pub fn dismissal_hazard(
+    cfg: &SeparationConfig,
+    p: Option<&Personality>,
+    m: Option<&Employment>,
+) -> f64 {
+    let t = cfg.performance_threshold;
+    let perf = performance(p, m);
+    if t <= 0.0 || perf >= t {
+        return 0.0;
+    }
+    cfg.dismissal_rate_per_year * (t - perf) / t
+}
+
+/// Take `person` out of the role, make them `Unemployed` again and tell
+/// everyone who cares. Dismissals and layoffs count as a job loss.
+#[allow(clippy::too_many_arguments)]
+pub fn separate(
+    commands: &mut Commands,
+    job_entity: Entity,
+    job: &mut Job,
+    role_index: usize,
+    person: Entity,
+    reason: SeparationReason,
+    separated: &mut EventWriter<Separated>,
+    life: &mut EventWriter<LifeEvent>,
+) {
+    if let Some((_, members)) = job.roles.get_mut(role_index) {
+        members.retain(|&m| m != person);
+    }
+    release(
+        commands, job_entity, role_index, person, reason, separated, life,
+    );
+}
+
+/// Everything `separate` does besides editing the `Job`, for callers that
+/// already dropped the member themselves.
+pub fn release(
+    commands: &mut Commands,
+    job_entity: Entity,
+    role_index: usize,
+    person: Entity,
+    reason: SeparationReason,
+    separated: &mut EventWriter<Separated>,
+    life: &mut EventWriter<LifeEvent>,
+) {
+    if let Ok(mut ec) = commands.get_entity(person) {
+        ec.queue(move |mut e: EntityWorldMut| {
+            // a late layoff must not undo a hire made in the meantime
+            if e.get::<Employment>().is_none_or(|emp| emp.job == job_entity) {
+                e.remove::<Employment>().insert(Unemployed);
+            }
+        });
+    }
+    separated.write(Separated {
+        person,
+        job: job_entity,
+        role_index,
+        reason,
+    });
+    if matches!(
+        reason,
+        SeparationReason::Dismissed | SeparationReason::LaidOff
+    ) {
+        life.write(LifeEvent {
+            entity: person,
+            kind: LifeEventKind::JobLoss,
+        });
+    }
+}
+
+/// Lay off the most recent hires of any role holding more members than
+/// its `max`, e.g. after the role was shrunk.
+pub fn lay_off_excess(
+    mut commands: Commands,
+    mut jobs: Query<(Entity, &mut Job)>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut separated: EventWriter<Separated>,
+    mut life: EventWriter<LifeEvent>,
+) {
+    let over: Vec<Entity> = jobs
+        .iter()
+        .filter(|(_, job)| job.roles.iter().any(|(s, m)| m.len() > s.max as usize))
+        .map(|(e, _)| e)
+        .collect();
+    for e in over {
+        let Ok((_, mut job)) = jobs.get_mut(e) else {
+            continue;
+        };
+        for i in 0..job.roles.len() {
+            let (spec, members) = &mut job.roles[i];
+            while members.len() > spec.max as usize {
+                let Some(person) = members.pop() else {
+                    break;
+                };
+                release(
+                    &mut commands,
+                    e,
+                    i,
+                    person,
+                    SeparationReason::LaidOff,
+                    &mut separated,
+                    &mut life,
+                );
+            }
+        }
+        dirty.write(VacancyDirty { job: e });
+    }
+}
+
+/// Lay off members a catalog reload or `sync_employment` already took off
+/// the roster.
+pub fn lay_off_requested(
+    mut commands: Commands,
+    mut requests: EventReader<LayoffRequest>,
+    mut separated: EventWriter<Separated>,
+    mut life: EventWriter<LifeEvent>,
+) {
+    for req in requests.read() {
+        release(
+            &mut commands,
+            req.job,
+            req.role_index,
+            req.person,
+            SeparationReason::LaidOff,
+            &mut separated,
+            &mut life,
+        );
+    }
+}
+
+/// Each member may quit, or else be dismissed, with probability
+/// `1 - exp(-hazard * dt)` this frame. Compulsory roles are exempt.
+#[allow(clippy::too_many_arguments)]
+pub fn quit_and_dismiss(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<SeparationConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut jobs: Query<(Entity, &mut Job)>,
+    people: Query<(Option<&Personality>, Option<&Employment>)>,
+    mut dirty: EventWriter<VacancyDirty>,
+    mut separated: EventWriter<Separated>,
+    mut life: EventWriter<LifeEvent>,
+) {
+    let dt = time.delta_secs_f64() * aging.years_per_sec;
+    if dt <= 0.0 {
+        return;
+    }
+    let mut order: Vec<Entity> = jobs.iter().map(|(e, _)| e).collect();
+    order.sort();
+    for e in order {
+        let Ok((_, mut job)) = jobs.get_mut(e) else {
+            continue;
+        };
+        let mut leaving: Vec<(usize, Entity, SeparationReason)> = Vec::new();
+        for (i, (spec, members)) in job.roles.iter().enumerate() {
+            if spec.compulsory {
+                continue;
+            }
+            for &m in members {
+                let (p, matched) = people.get(m).unwrap_or((None, None));
+                let quit = 1.0 - (-quit_hazard(&cfg, p, matched) * dt).exp();
+                let fire = 1.0 - (-dismissal_hazard(&cfg, p, matched) * dt).exp();
+                if rng.0.random_bool(quit.clamp(0.0, 1.0)) {
+                    leaving.push((i, m, SeparationReason::Quit));
+                } else if rng.0.random_bool(fire.clamp(0.0, 1.0)) {
+                    leaving.push((i, m, SeparationReason::Dismissed));
+                }
+            }
+        }
+        if leaving.is_empty() {
+            continue;
+        }
+        for (i, m, reason) in leaving {
+            separate(
+                &mut commands,
+                e,
+                &mut job,
+                i,
+                m,
+                reason,
+                &mut separated,
+                &mut life,
+            );
+        }
+        dirty.write(VacancyDirty { job: e });
+    }
+}
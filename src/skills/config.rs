@@ -0,0 +1,22 @@
+use bevy_ecs::prelude::*;
+
+/// Rates are per simulated year.
+#[derive(Resource, Clone, Debug)]
+pub struct SkillConfig {
+    /// Practised skills close this share of the gap to 1 per year.
+    pub learning_rate_per_year: f64,
+    /// Multiplier on learning per unit of intelligence above 0.5.
+    pub intelligence_gain: f64,
+    /// Unpractised skills lose this share of their level per year.
+    pub decay_per_year: f64,
+}
+
+impl Default for SkillConfig {
+    fn default() -> Self {
+        Self {
+            learning_rate_per_year: 0.3,
+            intelligence_gain: 1.0,
+            decay_per_year: 0.03,
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod config;
+pub mod plugin;
+pub mod system;
+
+pub use config::SkillConfig;
+pub use plugin::SkillsPlugin;
+pub use system::accumulate_skills;
@@ -0,0 +1,18 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use crate::person::AgingConfig;
+use crate::skills::config::SkillConfig;
+use crate::skills::system::accumulate_skills;
+
+/// On-the-job learning: `Experience` and the `Skills` a role trains grow
+/// with time in the role, and unused skills slowly decay.
+pub struct SkillsPlugin;
+
+impl Plugin for SkillsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SkillConfig>()
+            .init_resource::<AgingConfig>()
+            .add_systems(Update, accumulate_skills);
+    }
+}
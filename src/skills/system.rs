@@ -0,0 +1,75 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::HashSet;
+
+use crate::jobs::{Absent, Employment, Job};
+use crate::person::{AgingConfig, Experience, Skills};
+use crate::personality::Personality;
+use crate::skills::config::SkillConfig;
+
+/// Employed people gain experience, and the skills their role trains
+/// approach 1 while they attend. Every other skill decays toward 0, so
+/// `Skills` carry over between jobs but fade when unused.
+#[allow(clippy::type_complexity)]
+pub fn accumulate_skills(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<SkillConfig>,
+    jobs: Query<&Job>,
+    mut people: Query<
+        (
+            Entity,
+            Option<&Employment>,
+            Option<&mut Skills>,
+            Option<&mut Experience>,
+            Option<&Personality>,
+            Has<Absent>,
+        ),
+        Or<(With<Employment>, With<Skills>)>,
+    >,
+) {
+    let dt = time.delta_secs_f64() * aging.years_per_sec;
+    if dt <= 0.0 {
+        return;
+    }
+    let decay = (-cfg.decay_per_year * dt).exp();
+    for (e, employment, skills, experience, personality, absent) in people.iter_mut() {
+        let trains: &[String] = employment
+            .and_then(|emp| jobs.get(emp.job).ok()?.roles.get(emp.role_index))
+            .map_or(&[], |(spec, _)| spec.trains.as_slice());
+        let practised: HashSet<&str> = trains.iter().map(String::as_str).collect();
+        let gain =
+            1.0 + cfg.intelligence_gain * (personality.map_or(0.5, |p| p.intelligence) - 0.5);
+        let learn = 1.0 - (-(cfg.learning_rate_per_year * gain).max(0.0) * dt).exp();
+
+        let mut fresh = Skills::default();
+        let had_skills = skills.is_some();
+        let levels = match skills {
+            Some(s) => s.into_inner(),
+            None => &mut fresh,
+        };
+        for (name, level) in levels.0.iter_mut() {
+            if !practised.contains(name.as_str()) {
+                *level = (*level as f64 * decay) as f32;
+            }
+        }
+        // absence pauses the role's skills: no learning, no decay
+        for name in practised.iter().filter(|_| !absent) {
+            let level = levels.level(name) as f64;
+            levels.set(*name, (level + (1.0 - level) * learn) as f32);
+        }
+        if !had_skills && !fresh.0.is_empty() {
+            commands.entity(e).insert(fresh);
+        }
+
+        if employment.is_some() {
+            match experience {
+                Some(mut x) => x.years += dt as f32,
+                None => {
+                    commands.entity(e).insert(Experience { years: dt as f32 });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use bevy_ecs::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::graph::AttrValue;
+use crate::jobs::Employment;
+use crate::person::Person;
+use crate::personality::{Personality, Trait};
+
+/// Age, personality and employment of a node, whichever it has; pass to
+/// `GraphExportPlugin::attributes` to describe people in exports.
+pub fn person_attributes(entity: EntityRef) -> BTreeMap<String, AttrValue> {
+    let mut attrs = BTreeMap::new();
+    if let Some(p) = entity.get::<Person>() {
+        attrs.insert("age".into(), AttrValue::Float(p.age as f64));
+    }
+    if let Some(p) = entity.get::<Personality>() {
+        for t in Trait::ALL {
+            attrs.insert(format!("{t:?}").to_lowercase(), AttrValue::Float(p.get(t)));
+        }
+    }
+    if entity.contains::<Person>() {
+        let job = entity.get::<Employment>();
+        attrs.insert("employed".into(), AttrValue::Bool(job.is_some()));
+        if let Some(m) = job {
+            attrs.insert("job".into(), AttrValue::Int(m.job.to_bits() as i64));
+            attrs.insert("role".into(), AttrValue::Int(m.role_index as i64));
+        }
+    }
+    attrs
+}
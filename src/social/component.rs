@@ -0,0 +1,47 @@
+use bevy_ecs::prelude::*;
+
+use crate::graph::EdgeWeight;
+
+/// Value of a social edge in `Graph<Tie>`. Ties are kept symmetric by
+/// `RelationshipPlugin::<Tie>::undirected()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tie {
+    /// In `[0, 1]`; non-kin ties below `SocialConfig::min_strength` are dropped.
+    pub strength: f32,
+    /// Family ties never decay.
+    pub kin: bool,
+    /// Both ends currently share a `Job`; set and cleared by `workplace_ties`.
+    pub colleague: bool,
+}
+
+impl Tie {
+    pub fn friend(strength: f32) -> Self {
+        Self {
+            strength,
+            kin: false,
+            colleague: false,
+        }
+    }
+
+    pub fn kin(strength: f32) -> Self {
+        Self {
+            strength,
+            kin: true,
+            colleague: false,
+        }
+    }
+
+    pub fn colleague(strength: f32) -> Self {
+        Self {
+            strength,
+            kin: false,
+            colleague: true,
+        }
+    }
+}
+
+impl EdgeWeight for Tie {
+    fn weight(&self) -> f64 {
+        self.strength as f64
+    }
+}
@@ -0,0 +1,77 @@
+use bevy_ecs::prelude::*;
+
+/// How friendships are chosen, both when seeding a whole network and when
+/// people make new friends over time.
+#[derive(Clone, Debug)]
+pub enum TieModel {
+    /// Erdős–Rényi: every pair linked with the same probability.
+    Random { mean_degree: f64 },
+    /// Watts–Strogatz: ring lattice of `k` neighbours, each edge rewired with
+    /// probability `rewire`. New friends are friends-of-friends unless rewired.
+    SmallWorld { k: usize, rewire: f64 },
+    /// Barabási–Albert: each newcomer attaches to `m` people, favouring the well-connected.
+    PreferentialAttachment { m: usize },
+    /// Link probability falls off with age gap and personality distance.
+    Homophily {
+        mean_degree: f64,
+        /// Years of age gap that cut the affinity by a factor of e.
+        age_scale: f64,
+        /// Euclidean trait distance that cuts the affinity by a factor of e.
+        personality_scale: f64,
+    },
+}
+
+#[derive(Resource, Clone, Debug)]
+pub struct SocialConfig {
+    pub model: TieModel,
+    /// Generate a network over everyone present at the first `Update`.
+    pub seed_on_startup: bool,
+    /// People with fewer friends than this keep looking for new ones.
+    pub target_degree: usize,
+    /// Chance per simulated year of making a friend while below target.
+    pub formation_per_year: f64,
+    /// Younger people only have family ties.
+    pub min_age: f32,
+    pub initial_strength: f32,
+    pub family_strength: f32,
+    /// Friendship strength lost per simulated year.
+    pub decay_per_year: f32,
+    /// Strength regained per year, scaled by personality similarity in `[0, 1]`.
+    pub reinforce_per_year: f32,
+    pub min_strength: f32,
+    /// Chance per simulated year that two members of the same role who are
+    /// not yet tied become acquainted. Scaled by `cross_role_factor` for
+    /// members of different roles in the same job.
+    pub workplace_formation_per_year: f64,
+    /// Strength gained per year while sharing a role.
+    pub workplace_reinforce_per_year: f32,
+    pub cross_role_factor: f32,
+    /// Strength lost at once when two colleagues stop sharing a job; the tie
+    /// then fades like any other friendship.
+    pub workplace_leave_drop: f32,
+}
+
+impl Default for SocialConfig {
+    fn default() -> Self {
+        Self {
+            model: TieModel::Homophily {
+                mean_degree: 6.0,
+                age_scale: 10.0,
+                personality_scale: 0.5,
+            },
+            seed_on_startup: true,
+            target_degree: 6,
+            formation_per_year: 1.0,
+            min_age: 5.0,
+            initial_strength: 0.5,
+            family_strength: 1.0,
+            decay_per_year: 0.1,
+            reinforce_per_year: 0.1,
+            min_strength: 0.05,
+            workplace_formation_per_year: 2.0,
+            workplace_reinforce_per_year: 0.2,
+            cross_role_factor: 0.25,
+            workplace_leave_drop: 0.2,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+pub mod attributes;
+pub mod component;
+pub mod config;
+pub mod model;
+pub mod plugin;
+pub mod system;
+
+pub use attributes::person_attributes;
+pub use component::Tie;
+pub use config::{SocialConfig, TieModel};
+pub use model::{SocialNode, generate_ties};
+pub use plugin::SocialNetworkPlugin;
@@ -0,0 +1,206 @@
+use bevy_ecs::prelude::*;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::personality::TRAIT_COUNT;
+use crate::personality::analytics::euclidean;
+use crate::social::config::TieModel;
+
+/// What the tie models know about a person.
+#[derive(Clone, Debug)]
+pub struct SocialNode {
+    pub entity: Entity,
+    pub age: f32,
+    pub traits: Option<[f64; TRAIT_COUNT]>,
+}
+
+/// Affinity in `(0, 1]` between two people under the homophily model.
+pub fn affinity(a: &SocialNode, b: &SocialNode, age_scale: f64, personality_scale: f64) -> f64 {
+    let age = ((a.age - b.age).abs() as f64 / age_scale.max(f64::EPSILON))
+        .exp()
+        .recip();
+    let traits = match (a.traits, b.traits) {
+        (Some(x), Some(y)) => (euclidean(&x, &y) / personality_scale.max(f64::EPSILON))
+            .exp()
+            .recip(),
+        _ => 1.0,
+    };
+    age * traits
+}
+
+/// Generate a whole network over `nodes` with `model`. Returns index pairs
+/// `(i, j)` with `i < j`, each at most once.
+pub fn generate_ties<R: Rng + ?Sized>(
+    model: &TieModel,
+    nodes: &[SocialNode],
+    rng: &mut R,
+) -> Vec<(usize, usize)> {
+    let n = nodes.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    let key = |i: usize, j: usize| (i.min(j), i.max(j));
+
+    match *model {
+        TieModel::Random { mean_degree } => {
+            let p = (mean_degree / (n - 1) as f64).clamp(0.0, 1.0);
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    if rng.random_bool(p) {
+                        edges.insert((i, j));
+                    }
+                }
+            }
+        }
+        TieModel::SmallWorld { k, rewire } => {
+            let half = (k / 2).min((n - 1) / 2).max(1);
+            let mut lattice = Vec::new();
+            for i in 0..n {
+                for d in 1..=half {
+                    lattice.push((i, (i + d) % n));
+                }
+            }
+            for (i, j) in lattice {
+                let mut j = j;
+                if rng.random_bool(rewire.clamp(0.0, 1.0)) {
+                    // pick a fresh endpoint; keep the lattice edge if none is free
+                    for _ in 0..n {
+                        let c = rng.random_range(0..n);
+                        if c != i && !edges.contains(&key(i, c)) {
+                            j = c;
+                            break;
+                        }
+                    }
+                }
+                if i != j {
+                    edges.insert(key(i, j));
+                }
+            }
+        }
+        TieModel::PreferentialAttachment { m } => {
+            let m = m.clamp(1, n - 1);
+            // seed clique of m + 1 people
+            let mut targets: Vec<usize> = Vec::new();
+            for i in 0..=m {
+                for j in (i + 1)..=m {
+                    edges.insert((i, j));
+                    targets.extend([i, j]);
+                }
+            }
+            for i in (m + 1)..n {
+                let mut chosen = HashSet::new();
+                while chosen.len() < m {
+                    chosen.insert(targets[rng.random_range(0..targets.len())]);
+                }
+                let mut chosen: Vec<_> = chosen.into_iter().collect();
+                chosen.sort();
+                for j in chosen {
+                    edges.insert(key(i, j));
+                    targets.extend([i, j]);
+                }
+            }
+        }
+        TieModel::Homophily {
+            mean_degree,
+            age_scale,
+            personality_scale,
+        } => {
+            let mut w = Vec::with_capacity(n * (n - 1) / 2);
+            for i in 0..n {
+                for j in (i + 1)..n {
+                    w.push((
+                        i,
+                        j,
+                        affinity(&nodes[i], &nodes[j], age_scale, personality_scale),
+                    ));
+                }
+            }
+            let total: f64 = w.iter().map(|&(_, _, a)| a).sum();
+            // scale so the expected degree is `mean_degree`
+            let c = if total > 0.0 {
+                mean_degree * n as f64 / (2.0 * total)
+            } else {
+                0.0
+            };
+            for (i, j, a) in w {
+                if rng.random_bool((c * a).clamp(0.0, 1.0)) {
+                    edges.insert((i, j));
+                }
+            }
+        }
+    }
+
+    let mut out: Vec<_> = edges.into_iter().collect();
+    out.sort();
+    out
+}
+
+/// Pick one new friend for `nodes[i]` among `candidates` (indices into
+/// `nodes`, excluding `i` and existing friends). `adjacency[j]` lists the
+/// current friends of `nodes[j]`.
+pub fn pick_partner<R: Rng + ?Sized>(
+    model: &TieModel,
+    i: usize,
+    nodes: &[SocialNode],
+    adjacency: &[Vec<usize>],
+    candidates: &[usize],
+    rng: &mut R,
+) -> Option<usize> {
+    if candidates.is_empty() {
+        return None;
+    }
+    let weighted = |weights: Vec<f64>, rng: &mut R| -> Option<usize> {
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 {
+            return None;
+        }
+        let mut u = rng.random::<f64>() * total;
+        for (c, w) in candidates.iter().zip(weights) {
+            u -= w;
+            if u <= 0.0 {
+                return Some(*c);
+            }
+        }
+        candidates.last().copied()
+    };
+
+    match *model {
+        TieModel::Random { .. } => Some(candidates[rng.random_range(0..candidates.len())]),
+        TieModel::SmallWorld { rewire, .. } => {
+            if !rng.random_bool(rewire.clamp(0.0, 1.0)) {
+                let fof: HashSet<usize> = adjacency[i]
+                    .iter()
+                    .flat_map(|&f| adjacency[f].iter().copied())
+                    .collect();
+                let closing: Vec<usize> = candidates
+                    .iter()
+                    .copied()
+                    .filter(|c| fof.contains(c))
+                    .collect();
+                if !closing.is_empty() {
+                    return Some(closing[rng.random_range(0..closing.len())]);
+                }
+            }
+            Some(candidates[rng.random_range(0..candidates.len())])
+        }
+        TieModel::PreferentialAttachment { .. } => weighted(
+            candidates
+                .iter()
+                .map(|&c| adjacency[c].len() as f64 + 1.0)
+                .collect(),
+            rng,
+        ),
+        TieModel::Homophily {
+            age_scale,
+            personality_scale,
+            ..
+        } => weighted(
+            candidates
+                .iter()
+                .map(|&c| affinity(&nodes[i], &nodes[c], age_scale, personality_scale))
+                .collect(),
+            rng,
+        ),
+    }
+}
@@ -0,0 +1,42 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::common_conditions::run_once;
+
+use crate::baby_spawner::BabyBorn;
+use crate::baby_spawner::system::{GameRNG, spawn_babies};
+use crate::graph::RelationshipPlugin;
+use crate::person::AgingConfig;
+use crate::personality::system::assign_personalities;
+use crate::social::component::Tie;
+use crate::social::config::SocialConfig;
+use crate::social::system::{
+    attach_social_graph, form_ties, maintain_ties, seed_social_network, tie_family_on_birth,
+    workplace_ties,
+};
+
+pub struct SocialNetworkPlugin;
+
+impl Plugin for SocialNetworkPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RelationshipPlugin::<Tie>::undirected())
+            .init_resource::<SocialConfig>()
+            .init_resource::<AgingConfig>()
+            .init_resource::<GameRNG>()
+            .add_event::<BabyBorn>()
+            .add_systems(
+                Update,
+                (
+                    // seeded after the first personalities exist, so homophily can use them
+                    seed_social_network
+                        .run_if(run_once)
+                        .after(assign_personalities),
+                    tie_family_on_birth.after(spawn_babies),
+                    attach_social_graph,
+                    workplace_ties,
+                    maintain_ties,
+                    form_ties,
+                )
+                    .chain(),
+            );
+    }
+}
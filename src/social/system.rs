@@ -0,0 +1,345 @@
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+use crate::baby_spawner::BabyBorn;
+use crate::baby_spawner::system::GameRNG;
+use crate::graph::Graph;
+use crate::jobs::{Absent, Job};
+use crate::person::{AgingConfig, Parents, Person};
+use crate::personality::analytics::euclidean;
+use crate::personality::{Personality, TRAIT_COUNT};
+use crate::social::component::Tie;
+use crate::social::config::SocialConfig;
+use crate::social::model::{SocialNode, generate_ties, pick_partner};
+
+/// Set `tie` on both ends. Missing graphs are skipped.
+pub fn link(graphs: &mut Query<&mut Graph<Tie>>, a: Entity, b: Entity, tie: Tie) {
+    if a == b {
+        return;
+    }
+    if let Ok([mut ga, mut gb]) = graphs.get_many_mut([a, b]) {
+        ga.set(b, tie);
+        gb.set(a, tie);
+    }
+}
+
+/// Similarity in `[0, 1]` from Euclidean trait distance on the unit scale.
+fn similarity(a: Option<&Personality>, b: Option<&Personality>) -> f32 {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let d = euclidean(&a.as_array(), &b.as_array()) / (TRAIT_COUNT as f64).sqrt();
+            (1.0 - d).clamp(0.0, 1.0) as f32
+        }
+        _ => 0.5,
+    }
+}
+
+/// Newborns are tied to their parents and to full siblings.
+pub fn tie_family_on_birth(
+    mut commands: Commands,
+    cfg: Res<SocialConfig>,
+    mut born: EventReader<BabyBorn>,
+    families: Query<(Entity, &Parents)>,
+    mut graphs: Query<&mut Graph<Tie>>,
+) {
+    let tie = Tie::kin(cfg.family_strength);
+    for ev in born.read() {
+        let Some((a, b)) = ev.parents else {
+            continue;
+        };
+        let mut kin = vec![a, b];
+        kin.extend(
+            families
+                .iter()
+                .filter(|(e, p)| *e != ev.entity && p.contains(a) && p.contains(b))
+                .map(|(e, _)| e),
+        );
+
+        let mut own = Graph::default();
+        for k in kin {
+            if let Ok(mut g) = graphs.get_mut(k) {
+                g.set(ev.entity, tie);
+                own.set(k, tie);
+            }
+        }
+        if let Ok(mut e) = commands.get_entity(ev.entity) {
+            e.insert(own);
+        }
+    }
+}
+
+/// Every person carries a (possibly empty) `Graph<Tie>`.
+pub fn attach_social_graph(
+    mut commands: Commands,
+    people: Query<Entity, (With<Person>, Without<Graph<Tie>>)>,
+) {
+    for e in people.iter() {
+        commands.entity(e).insert(Graph::<Tie>::default());
+    }
+}
+
+/// Friendships fade by `decay_per_year` and are topped up in proportion to
+/// how alike the two friends are; ties too weak to matter are dropped.
+pub fn maintain_ties(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<SocialConfig>,
+    traits: Query<&Personality>,
+    mut graphs: Query<(Entity, &mut Graph<Tie>)>,
+) {
+    let dy = (time.delta_secs_f64() * aging.years_per_sec) as f32;
+    if dy <= 0.0 {
+        return;
+    }
+    for (me, mut g) in graphs.iter_mut() {
+        let mine = traits.get(me).ok();
+        // read-only pass so graphs whose ties are unchanged are not marked changed
+        let mut updates: Vec<(Entity, Option<f32>)> = Vec::new();
+        for (other, tie) in g.iter() {
+            if tie.kin {
+                continue;
+            }
+            let sim = similarity(mine, traits.get(other).ok());
+            let strength = (tie.strength - cfg.decay_per_year * dy
+                + cfg.reinforce_per_year * sim * dy)
+                .clamp(0.0, 1.0);
+            if strength < cfg.min_strength {
+                updates.push((other, None));
+            } else if strength != tie.strength {
+                updates.push((other, Some(strength)));
+            }
+        }
+        for (other, strength) in updates {
+            match strength {
+                Some(s) => {
+                    if let Some(t) = g.get_mut(&other) {
+                        t.strength = s;
+                    }
+                }
+                None => {
+                    g.remove(&other);
+                }
+            }
+        }
+    }
+}
+
+/// Co-members of a job get to know each other and grow closer while they
+/// work together. Ties that lose their shared job drop `workplace_leave_drop`
+/// and are left to `maintain_ties`.
+#[allow(clippy::too_many_arguments)]
+pub fn workplace_ties(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<SocialConfig>,
+    mut rng: ResMut<GameRNG>,
+    mut pairs: Local<Vec<((Entity, Entity), f32)>>,
+    jobs: Query<&Job>,
+    rostered: Query<(), Changed<Job>>,
+    mut closed: RemovedComponents<Job>,
+    absent: Query<(), With<Absent>>,
+    mut graphs: Query<(Entity, &mut Graph<Tie>)>,
+) {
+    // pairs only move when a roster does
+    if !rostered.is_empty() || closed.read().count() > 0 {
+        *pairs = colleague_pairs(&jobs, cfg.cross_role_factor);
+    }
+    let dy = time.delta_secs_f64() * aging.years_per_sec;
+    if dy <= 0.0 {
+        return;
+    }
+
+    for &((a, b), factor) in pairs.iter() {
+        // absence pauses the tie without ending it
+        if absent.contains(a) || absent.contains(b) {
+            continue;
+        }
+        let Ok([(_, mut ga), (_, mut gb)]) = graphs.get_many_mut([a, b]) else {
+            continue;
+        };
+        let tie = match ga.get(&b) {
+            Some(t) => Tie {
+                strength: (t.strength + cfg.workplace_reinforce_per_year * factor * dy as f32)
+                    .min(1.0),
+                colleague: true,
+                ..*t
+            },
+            None => {
+                let p = (cfg.workplace_formation_per_year * factor as f64 * dy).clamp(0.0, 1.0);
+                if !rng.0.random_bool(p) {
+                    continue;
+                }
+                Tie::colleague(cfg.initial_strength)
+            }
+        };
+        if ga.get(&b) != Some(&tie) {
+            ga.set(b, tie);
+        }
+        if gb.get(&a) != Some(&tie) {
+            gb.set(a, tie);
+        }
+    }
+
+    let current: HashSet<(Entity, Entity)> = pairs.iter().map(|&(k, _)| k).collect();
+    for (me, mut g) in graphs.iter_mut() {
+        // scan read-only first so graphs without departures are not marked changed
+        let left: Vec<Entity> = g
+            .iter()
+            .filter(|&(other, t)| t.colleague && !current.contains(&(me.min(other), me.max(other))))
+            .map(|(other, _)| other)
+            .collect();
+        for other in left {
+            if let Some(t) = g.get_mut(&other) {
+                t.colleague = false;
+                if !t.kin {
+                    t.strength = (t.strength - cfg.workplace_leave_drop).max(0.0);
+                }
+            }
+        }
+    }
+}
+
+/// Unordered pairs (a < b) sharing a job, sorted; same role beats cross role.
+fn colleague_pairs(jobs: &Query<&Job>, cross_role_factor: f32) -> Vec<((Entity, Entity), f32)> {
+    let mut pairs: HashMap<(Entity, Entity), f32> = HashMap::new();
+    for job in jobs.iter() {
+        for (r, (_, members)) in job.roles.iter().enumerate() {
+            for (r2, (_, others)) in job.roles.iter().enumerate().skip(r) {
+                let factor = if r == r2 { 1.0 } else { cross_role_factor };
+                for &a in members {
+                    for &b in others {
+                        if a == b {
+                            continue;
+                        }
+                        let f = pairs.entry((a.min(b), a.max(b))).or_default();
+                        *f = f.max(factor);
+                    }
+                }
+            }
+        }
+    }
+    let mut pairs: Vec<_> = pairs.into_iter().collect();
+    pairs.sort_by_key(|&(k, _)| k);
+    pairs
+}
+
+#[allow(clippy::type_complexity)]
+fn snapshot(
+    people: &Query<(Entity, &Person, Option<&Personality>), With<Graph<Tie>>>,
+    min_age: f32,
+) -> Vec<SocialNode> {
+    let mut nodes: Vec<SocialNode> = people
+        .iter()
+        .filter(|(_, p, _)| p.age >= min_age)
+        .map(|(entity, p, t)| SocialNode {
+            entity,
+            age: p.age,
+            traits: t.map(|t| t.as_array()),
+        })
+        .collect();
+    nodes.sort_by_key(|n| n.entity);
+    nodes
+}
+
+/// People below `target_degree` friends occasionally make a new one, chosen
+/// by the configured `TieModel`.
+#[allow(clippy::type_complexity)]
+pub fn form_ties(
+    time: Res<Time<Real>>,
+    aging: Res<AgingConfig>,
+    cfg: Res<SocialConfig>,
+    mut rng: ResMut<GameRNG>,
+    people: Query<(Entity, &Person, Option<&Personality>), With<Graph<Tie>>>,
+    mut graphs: Query<&mut Graph<Tie>>,
+) {
+    let dy = time.delta_secs_f64() * aging.years_per_sec;
+    let p = (cfg.formation_per_year * dy).clamp(0.0, 1.0);
+    if p <= 0.0 {
+        return;
+    }
+
+    let nodes = snapshot(&people, cfg.min_age);
+    let index: HashMap<Entity, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.entity, i))
+        .collect();
+    let mut adjacency: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|n| {
+            graphs
+                .get(n.entity)
+                .map(|g| {
+                    let mut v: Vec<usize> = g
+                        .iter()
+                        .filter(|(_, t)| !t.kin)
+                        .filter_map(|(e, _)| index.get(&e).copied())
+                        .collect();
+                    v.sort();
+                    v
+                })
+                .unwrap_or_default()
+        })
+        .collect();
+
+    for i in 0..nodes.len() {
+        if adjacency[i].len() >= cfg.target_degree || !rng.0.random_bool(p) {
+            continue;
+        }
+        let candidates: Vec<usize> = (0..nodes.len())
+            .filter(|&c| c != i && !adjacency[i].contains(&c))
+            .collect();
+        if let Some(j) = pick_partner(&cfg.model, i, &nodes, &adjacency, &candidates, &mut rng.0) {
+            link(
+                &mut graphs,
+                nodes[i].entity,
+                nodes[j].entity,
+                Tie::friend(cfg.initial_strength),
+            );
+            adjacency[i].push(j);
+            adjacency[j].push(i);
+        }
+    }
+}
+
+/// Generate a whole friendship network over everyone old enough. Runs once,
+/// on the first `Update` after personalities are assigned.
+pub fn seed_social_network(world: &mut World) {
+    let cfg = world.resource::<SocialConfig>().clone();
+    if !cfg.seed_on_startup {
+        return;
+    }
+    let mut q = world.query::<(Entity, &Person, Option<&Personality>)>();
+    let mut nodes: Vec<SocialNode> = q
+        .iter(world)
+        .filter(|(_, p, _)| p.age >= cfg.min_age)
+        .map(|(entity, p, t)| SocialNode {
+            entity,
+            age: p.age,
+            traits: t.map(|t| t.as_array()),
+        })
+        .collect();
+    nodes.sort_by_key(|n| n.entity);
+
+    let pairs = {
+        let mut rng = world.resource_mut::<GameRNG>();
+        generate_ties(&cfg.model, &nodes, &mut rng.0)
+    };
+    let tie = Tie::friend(cfg.initial_strength);
+    for n in &nodes {
+        if world.get::<Graph<Tie>>(n.entity).is_none() {
+            world.entity_mut(n.entity).insert(Graph::<Tie>::default());
+        }
+    }
+    for (i, j) in pairs {
+        let (a, b) = (nodes[i].entity, nodes[j].entity);
+        if let Some(mut g) = world.get_mut::<Graph<Tie>>(a) {
+            g.set(b, tie);
+        }
+        if let Some(mut g) = world.get_mut::<Graph<Tie>>(b) {
+            g.set(a, tie);
+        }
+    }
+}
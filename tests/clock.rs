@@ -0,0 +1,18 @@
+// Test clock (shared helper, no tests of its own)
+//
+// The sim runs on `Time<Real>`, and the first real tick has no delta.
+// `step` advances that clock by an exact amount and runs one update, so a
+// test can say "half a simulated year passes" without sleeping.
+
+use bevy_app::prelude::*;
+use bevy_time::{Real, Time};
+use std::time::Duration;
+
+#[allow(dead_code)]
+// Advance `Time<Real>` by `secs` and run one update.
+pub fn step(app: &mut App, secs: f64) {
+    let mut time = app.world_mut().resource_mut::<Time<Real>>();
+    let last = time.last_update().unwrap_or_else(|| time.startup());
+    time.update_with_instant(last + Duration::from_secs_f64(secs));
+    app.update();
+}
@@ -0,0 +1,189 @@
+// Diffusion over Graph edges (what the tests mean)
+//
+// Fixture: a path a — b — c — d of unit-weight undirected f32 edges.
+//
+//   (1) Transmission is 1 for average personalities and rises with the
+//       sender's extraversion and the receiver's openness
+//   (2) Independent cascade with p = 1 advances one hop per round, and
+//       Records' adoption curve follows it
+//   (3) Linear threshold adopts exactly when adopting neighbours reach the threshold
+//   (4) DeGroot averaging reaches consensus; open receivers move further per round
+//   (5) The adoption curve stays bounded, thinning old rounds but keeping the ends
+//   (6) RecordsPlugin runs without the diffusion, epidemic, payroll and
+//       personality plugins, and simply records nothing for them
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::baby_spawner::BabyBorn;
+use simrs::baby_spawner::system::GameRNG;
+use simrs::diffusion::{
+    Adopted, DiffusionConfig, DiffusionModel, DiffusionPlugin, DiffusionStep, Opinion, Threshold,
+    transmission,
+};
+use simrs::graph::Graph;
+use simrs::mortality::Death;
+use simrs::personality::Personality;
+use simrs::records::{ADOPTION_CURVE_MAX, Records, RecordsPlugin, record_diffusion};
+
+fn app(model: DiffusionModel) -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(5)));
+    app.insert_resource(DiffusionConfig {
+        model,
+        interval_secs: 0.0,
+        initial_adopters: 0,
+        ..Default::default()
+    });
+    app.insert_resource(Records::default());
+    app.add_plugins(DiffusionPlugin::<f32>::new())
+        .add_systems(Update, record_diffusion);
+    app
+}
+
+fn path(world: &mut World) -> [Entity; 4] {
+    let n: [Entity; 4] = std::array::from_fn(|_| world.spawn(Graph::<f32>::default()).id());
+    for w in n.windows(2) {
+        world.get_mut::<Graph<f32>>(w[0]).unwrap().set(w[1], 1.0);
+        world.get_mut::<Graph<f32>>(w[1]).unwrap().set(w[0], 1.0);
+    }
+    n
+}
+
+fn with(extraversion: f64, openness: f64) -> Personality {
+    let mut p = Personality::from_array([0.5; 6]);
+    p.extraversion = extraversion;
+    p.openness = openness;
+    p
+}
+
+#[test]
+fn personality_modulates_transmission() {
+    let cfg = DiffusionConfig::default();
+    let avg = with(0.5, 0.5);
+    assert_eq!(transmission(&cfg, None, None), 1.0);
+    assert_eq!(transmission(&cfg, Some(&avg), Some(&avg)), 1.0);
+    assert!(transmission(&cfg, Some(&with(0.9, 0.5)), Some(&avg)) > 1.0);
+    assert!(transmission(&cfg, Some(&avg), Some(&with(0.5, 0.9))) > 1.0);
+    assert!(transmission(&cfg, Some(&with(0.1, 0.5)), Some(&with(0.5, 0.1))) < 1.0);
+}
+
+#[test]
+fn cascade_advances_one_hop_per_round() {
+    let mut app = app(DiffusionModel::IndependentCascade { p: 1.0 });
+    let [a, b, c, d] = path(app.world_mut());
+    app.world_mut().entity_mut(a).insert(Adopted::new(0.0));
+
+    for (round, reached) in [b, c, d].into_iter().enumerate() {
+        app.update();
+        assert!(
+            app.world().get::<Adopted>(reached).is_some(),
+            "round {round}"
+        );
+    }
+    app.update(); // records read the last round's event
+    let curve: Vec<f32> = app
+        .world()
+        .resource::<Records>()
+        .adoption_curve
+        .iter()
+        .map(|&(_, s)| s)
+        .collect();
+    assert_eq!(curve[..3], [0.5, 0.75, 1.0]);
+}
+
+#[test]
+fn threshold_needs_enough_adopting_neighbours() {
+    let mut app = app(DiffusionModel::LinearThreshold);
+    let [a, b, c, d] = path(app.world_mut());
+    let world = app.world_mut();
+    world.entity_mut(a).insert(Adopted::new(0.0));
+    world.entity_mut(b).insert(Threshold(0.5)); // a is half of b's influence
+    world.entity_mut(c).insert(Threshold(0.6));
+    world.entity_mut(d).insert(Threshold(1.0));
+
+    app.update();
+    assert!(app.world().get::<Adopted>(b).is_some());
+    assert!(app.world().get::<Adopted>(c).is_none());
+    app.update();
+    assert!(app.world().get::<Adopted>(c).is_none()); // b is only half of c's influence
+    assert!(app.world().get::<Adopted>(d).is_none());
+}
+
+#[test]
+fn degroot_reaches_consensus_faster_when_open() {
+    let mut app = app(DiffusionModel::DeGroot { self_weight: 0.5 });
+    let world = app.world_mut();
+    let [a, b, ..] = path(world);
+    let (c, d) = {
+        let c = world.spawn((Graph::<f32>::default(), Opinion(0.0))).id();
+        let d = world
+            .spawn((Graph::<f32>::default(), Opinion(1.0), with(0.5, 1.0)))
+            .id();
+        world.get_mut::<Graph<f32>>(c).unwrap().set(d, 1.0);
+        world.get_mut::<Graph<f32>>(d).unwrap().set(c, 1.0);
+        (c, d)
+    };
+
+    app.update(); // opinions assigned to the path, one round for c — d
+    let open = 1.0 - app.world().get::<Opinion>(d).unwrap().0;
+    let closed = app.world().get::<Opinion>(c).unwrap().0;
+    assert!(open > closed, "open moved {open}, average moved {closed}");
+
+    for _ in 0..60 {
+        app.update();
+    }
+    let [x, y] = [c, d].map(|e| app.world().get::<Opinion>(e).unwrap().0);
+    assert!((x - y).abs() < 1e-6);
+    let spread = path_spread(&app, [a, b]);
+    assert!(spread < 1e-3);
+    assert!(app.world().resource::<Records>().mean_opinion > 0.0);
+}
+
+fn path_spread(app: &App, nodes: [Entity; 2]) -> f64 {
+    let [x, y] = nodes.map(|e| app.world().get::<Opinion>(e).unwrap().0);
+    (x - y).abs()
+}
+
+#[test]
+fn adoption_curve_is_bounded() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(Records::default());
+    app.add_event::<DiffusionStep>()
+        .add_systems(Update, record_diffusion);
+    let rounds = 3 * ADOPTION_CURVE_MAX;
+    for adopted in 0..rounds {
+        app.world_mut().send_event(DiffusionStep {
+            adopted,
+            population: rounds,
+            mean_opinion: None,
+        });
+    }
+    app.update();
+
+    let curve = &app.world().resource::<Records>().adoption_curve;
+    assert!(curve.len() <= ADOPTION_CURVE_MAX);
+    assert_eq!(curve.first().unwrap().1, 0.0);
+    assert_eq!(curve.last().unwrap().1, (rounds - 1) as f32 / rounds as f32);
+}
+
+#[test]
+fn records_run_without_the_plugins_they_observe() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default())
+        .insert_resource(Records::default())
+        .add_event::<BabyBorn>()
+        .add_event::<Death>()
+        .add_plugins(RecordsPlugin);
+    app.update();
+    app.update();
+    let records = app.world().resource::<Records>();
+    assert!(records.adoption_curve.is_empty());
+    assert!(records.archetypes.is_empty());
+    assert!(!app.world().contains_resource::<Events<DiffusionStep>>());
+}
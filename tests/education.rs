@@ -0,0 +1,165 @@
+// Education through school membership (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated year; a school whose student role
+// (age < 18, schooling 1/yr) reports to a teacher role; class size 2.
+//
+//   (1) Attending students gain a year of Education per year; absent ones don't
+//   (2) Too few teachers slow learning in proportion; none stops it
+//   (3) Students who age out graduate: they leave the role Unemployed, with a
+//       Graduated event carrying their years and no job-loss life event
+//   (4) Education then qualifies graduates for roles that require it
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::education::{EducationConfig, EducationPlugin, Graduated};
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Absent, Employment, Job};
+use simrs::person::{AgingConfig, Education};
+use simrs::personality::LifeEvent;
+use simrs::separation::{Separated, SeparationReason};
+use simrs::{GregslistPlugin, HiringManagerPlugin, VacancyDirty};
+
+mod clock;
+use clock::step;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(EducationConfig { class_size: 2.0 });
+    app.add_plugins(EducationPlugin);
+    step(&mut app, 0.0);
+    app
+}
+
+// A school with `students` pupils aged 10 and `teachers` adults.
+fn school(app: &mut App, students: usize, teachers: usize) -> (Entity, Vec<Entity>) {
+    let world = app.world_mut();
+    let mut job = Job::builder()
+        .add_role(0, 100)
+        .age_lt(18)
+        .schooling(1.0)
+        .reports_to(1)
+        .add_role(0, 10)
+        .age_gte(18)
+        .build();
+    let job_entity = world.spawn_empty().id();
+    let mut pupils = Vec::new();
+    for (role_index, n, age) in [(0, students, 10), (1, teachers, 40)] {
+        for _ in 0..n {
+            let e = world
+                .spawn((Age { years: age }, Employment::new(job_entity, role_index)))
+                .id();
+            job.roles[role_index].1.push(e);
+            if role_index == 0 {
+                pupils.push(e);
+            }
+        }
+    }
+    world.entity_mut(job_entity).insert(job);
+    (job_entity, pupils)
+}
+
+fn years(app: &App, e: Entity) -> f32 {
+    app.world().get::<Education>(e).map_or(0.0, |ed| ed.years)
+}
+
+#[test]
+fn attending_students_gain_education() {
+    let mut app = app();
+    let (_, pupils) = school(&mut app, 2, 1);
+    app.world_mut().entity_mut(pupils[1]).insert(Absent);
+
+    step(&mut app, 1.0);
+    step(&mut app, 0.5);
+    assert_abs_diff_eq!(years(&app, pupils[0]), 1.5, epsilon = 1e-4);
+    assert!(app.world().get::<Education>(pupils[1]).is_none());
+}
+
+#[test]
+fn crowded_classes_learn_slower() {
+    let mut app = app();
+    let (_, crowded) = school(&mut app, 4, 1);
+    let (_, untaught) = school(&mut app, 1, 0);
+
+    step(&mut app, 1.0);
+    assert_abs_diff_eq!(years(&app, crowded[0]), 0.5, epsilon = 1e-4);
+    assert_eq!(years(&app, untaught[0]), 0.0);
+}
+
+#[test]
+fn students_graduate_on_aging_out() {
+    let mut app = app();
+    let (job, pupils) = school(&mut app, 2, 1);
+    step(&mut app, 1.0);
+    app.world_mut().get_mut::<Age>(pupils[0]).unwrap().years = 18;
+    step(&mut app, 0.0);
+
+    assert_eq!(
+        app.world().get::<Job>(job).unwrap().roles[0].1,
+        vec![pupils[1]]
+    );
+    assert!(app.world().get::<Employment>(pupils[0]).is_none());
+    assert!(app.world().get::<Unemployed>(pupils[0]).is_some());
+    assert_abs_diff_eq!(years(&app, pupils[0]), 1.0, epsilon = 1e-4);
+
+    let graduated: Vec<Graduated> = app
+        .world()
+        .resource::<Events<Graduated>>()
+        .iter_current_update_events()
+        .copied()
+        .collect();
+    assert_eq!(graduated.len(), 1);
+    assert_eq!((graduated[0].person, graduated[0].job), (pupils[0], job));
+    assert_abs_diff_eq!(graduated[0].years, 1.0, epsilon = 1e-4);
+    let separated: Vec<SeparationReason> = app
+        .world()
+        .resource::<Events<Separated>>()
+        .iter_current_update_events()
+        .map(|s| s.reason)
+        .collect();
+    assert_eq!(separated, vec![SeparationReason::Graduated]);
+    let life = app.world().resource::<Events<LifeEvent>>();
+    assert_eq!(life.iter_current_update_events().count(), 0);
+}
+
+#[test]
+fn education_qualifies_graduates() {
+    let mut app = app();
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8));
+    let (_, pupils) = school(&mut app, 2, 1);
+    app.world_mut()
+        .entity_mut(pupils[0])
+        .insert(Education { years: 11.5 });
+    let office = app
+        .world_mut()
+        .spawn(
+            Job::builder()
+                .add_role(2, 2)
+                .age_gte(18)
+                .education(12.0)
+                .build(),
+        )
+        .id();
+    app.world_mut().send_event(VacancyDirty { job: office });
+
+    step(&mut app, 1.0);
+    for &p in &pupils {
+        app.world_mut().get_mut::<Age>(p).unwrap().years = 18;
+    }
+    step(&mut app, 0.0);
+    assert!(
+        pupils
+            .iter()
+            .all(|&p| app.world().get::<Unemployed>(p).is_some())
+    );
+
+    step(&mut app, 0.0);
+    let clerks = &app.world().get::<Job>(office).unwrap().roles[0].1;
+    assert_eq!(clerks, &vec![pupils[0]]);
+    assert!(app.world().get::<Unemployed>(pupils[1]).is_some());
+}
@@ -0,0 +1,192 @@
+// Employment on the person (what the tests mean)
+//
+//   (1) A hire carries Employment naming the job, role and hiring time in
+//       simulated years, matching the job's roster
+//   (2) Job::role_of / members / headcount answer roster questions
+//   (3) Separation removes Employment along with the seat
+//   (4) Despawned members leave rosters; people whose job is gone or no
+//       longer lists them are laid off, losing Employment
+//   (5) Someone put on a roster directly is given Employment
+//   (6) Keeping rosters in step leaves settled jobs unchanged
+//   (7) Re-seating someone keeps who referred them; their start date only
+//       restarts when they change jobs
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Employment, Job, sync_employment};
+use simrs::person::AgingConfig;
+use simrs::separation::{Separated, SeparationConfig, SeparationPlugin, SeparationReason};
+use simrs::{GregslistPlugin, HiringManagerPlugin, JobsPlugin};
+
+mod clock;
+use clock::step;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default())
+        .insert_resource(GameRNG(StdRng::seed_from_u64(1)))
+        .insert_resource(AgingConfig { years_per_sec: 1.0 })
+        .insert_resource(SeparationConfig {
+            quit_rate_per_year: 0.0,
+            dismissal_rate_per_year: 0.0,
+            ..Default::default()
+        });
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8))
+        .add_plugins(JobsPlugin)
+        .add_plugins(SeparationPlugin);
+    app
+}
+
+/// A job with a junior and a senior role, and one applicant for each.
+fn hire_two(app: &mut App) -> (Entity, Entity, Entity) {
+    let world = app.world_mut();
+    let job = world
+        .spawn(
+            Job::builder()
+                .add_role(1, 1)
+                .age_lt(30)
+                .add_role(1, 1)
+                .age_gte(30)
+                .build(),
+        )
+        .id();
+    let junior = world.spawn((Age { years: 20 }, Unemployed)).id();
+    let senior = world.spawn((Age { years: 40 }, Unemployed)).id();
+    step(app, 0.0);
+    step(app, 2.0);
+    (job, junior, senior)
+}
+
+#[test]
+fn hires_carry_employment() {
+    let mut app = app();
+    let (job, junior, senior) = hire_two(&mut app);
+    let world = app.world();
+
+    let emp = *world.get::<Employment>(senior).unwrap();
+    assert_eq!((emp.job, emp.role_index), (job, 1));
+    assert!(emp.since <= 2.0);
+    assert_eq!(world.get::<Employment>(junior).unwrap().role_index, 0);
+
+    let roster = world.get::<Job>(job).unwrap();
+    assert_eq!(roster.role_of(senior), Some(1));
+    assert_eq!(roster.role_of(job), None);
+    assert_eq!(roster.headcount(), 2);
+    assert_eq!(
+        roster.members().collect::<Vec<_>>(),
+        vec![(0, junior), (1, senior)]
+    );
+}
+
+#[test]
+fn separation_clears_employment() {
+    let mut app = app();
+    let (job, junior, senior) = hire_two(&mut app);
+
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[1].0.max = 0;
+    step(&mut app, 0.0);
+    let world = app.world();
+    assert!(world.get::<Employment>(senior).is_none());
+    assert!(world.get::<Unemployed>(senior).is_some());
+    assert!(world.get::<Employment>(junior).is_some());
+}
+
+#[test]
+fn rosters_and_employment_stay_in_step() {
+    let mut app = app();
+    let (job, junior, senior) = hire_two(&mut app);
+
+    app.world_mut().despawn(junior);
+    step(&mut app, 0.0);
+    let roster = app.world().get::<Job>(job).unwrap();
+    assert_eq!(roster.members().collect::<Vec<_>>(), vec![(1, senior)]);
+
+    app.world_mut().despawn(job);
+    step(&mut app, 0.0);
+    let world = app.world();
+    assert!(world.get::<Employment>(senior).is_none());
+    assert!(world.get::<Unemployed>(senior).is_some());
+
+    // the layoff is reported once separation sees the request
+    step(&mut app, 0.0);
+    let world = app.world();
+    let separated: Vec<_> = world
+        .resource::<Events<Separated>>()
+        .iter_current_update_events()
+        .map(|s| (s.person, s.job, s.reason))
+        .collect();
+    assert_eq!(separated, vec![(senior, job, SeparationReason::LaidOff)]);
+}
+
+#[test]
+fn rostered_people_are_given_employment() {
+    let mut app = app();
+    let (job, ..) = hire_two(&mut app);
+    let walk_in = app.world_mut().spawn((Age { years: 40 }, Unemployed)).id();
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[1].0.max = 2;
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[1]
+        .1
+        .push(walk_in);
+    step(&mut app, 1.0);
+
+    let world = app.world();
+    let emp = world.get::<Employment>(walk_in).unwrap();
+    assert_eq!((emp.job, emp.role_index), (job, 1));
+    assert!(emp.since >= 2.0);
+    assert!(world.get::<Unemployed>(walk_in).is_none());
+}
+
+#[derive(Resource, Default)]
+struct Touched(Vec<Entity>);
+
+#[test]
+fn settled_jobs_are_not_touched() {
+    let mut app = app();
+    app.init_resource::<Touched>().add_systems(
+        PostUpdate,
+        (|q: Query<Entity, Changed<Job>>, mut t: ResMut<Touched>| {
+            t.0 = q.iter().collect();
+        })
+        .after(sync_employment),
+    );
+    hire_two(&mut app);
+    step(&mut app, 1.0);
+    step(&mut app, 1.0);
+
+    assert!(app.world().resource::<Touched>().0.is_empty());
+}
+
+#[test]
+fn reseating_keeps_referral_and_restarts_on_a_new_job() {
+    let mut app = app();
+    let (job, junior, senior) = hire_two(&mut app);
+    let world = app.world_mut();
+    world.get_mut::<Employment>(junior).unwrap().referred_by = Some(senior);
+    let hired = world.get::<Employment>(senior).unwrap().since;
+
+    // the junior moves to another job, the senior to the other role
+    let other = world.spawn(Job::builder().add_role(0, 1).build()).id();
+    world.get_mut::<Job>(other).unwrap().roles[0].1.push(junior);
+    {
+        let mut roster = world.get_mut::<Job>(job).unwrap();
+        roster.roles[0].1 = vec![senior];
+        roster.roles[1].1.clear();
+    }
+    step(&mut app, 1.0);
+
+    let world = app.world();
+    let emp = world.get::<Employment>(junior).unwrap();
+    assert_eq!((emp.job, emp.role_index), (other, 0));
+    assert_eq!(emp.referred_by, Some(senior));
+    assert!(emp.since >= 3.0);
+    let emp = world.get::<Employment>(senior).unwrap();
+    assert_eq!((emp.job, emp.role_index), (job, 0));
+    assert_eq!(emp.since, hired);
+}
@@ -0,0 +1,231 @@
+// Compulsory enrolment (what the tests mean)
+//
+// Fixture: schools with one compulsory pupil role (6 <= age < 18) on a
+// line; children and adults live at given points.
+//
+//   (1) Eligible children are enrolled at the nearest school, without
+//       applying; adults are left alone
+//   (2) A full school sends children to the next nearest one with room
+//   (3) With no room anywhere children wait at the nearest school, and are
+//       seated first come, first served as seats open
+//   (4) A long enough waitlist opens another school of the same catalog
+//       type amid the waiting children, who are enrolled there
+//   (5) Enrolment passes run every `interval_secs`, not every frame
+//   (6) Only types the catalog can spawn are opened, so an unknown type
+//       does not block opening once it is defined
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::time::Duration;
+
+use simrs::enrolment::{Enrolled, EnrolmentConfig, EnrolmentPlugin, Waitlist};
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Employment, Job, JobCatalog, JobCatalogPlugin, JobType, SpawnJob};
+use simrs::person::Position;
+
+fn app(open_at: Option<usize>) -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(EnrolmentConfig {
+        open_at,
+        interval_secs: 0.0,
+    });
+    app.add_plugins(EnrolmentPlugin);
+    app
+}
+
+fn school(app: &mut App, x: f32, seats: u32) -> Entity {
+    let job = Job::builder()
+        .add_role(0, seats)
+        .age_between(6, 18)
+        .compulsory()
+        .build();
+    app.world_mut().spawn((job, Position::new(x, 0.0))).id()
+}
+
+fn person(app: &mut App, x: f32, years: u8) -> Entity {
+    app.world_mut()
+        .spawn((Age { years }, Position::new(x, 0.0)))
+        .id()
+}
+
+fn pupils(app: &App, school: Entity) -> Vec<Entity> {
+    app.world().get::<Job>(school).unwrap().roles[0].1.clone()
+}
+
+fn waiting(app: &App, school: Entity) -> Vec<Entity> {
+    app.world()
+        .get::<Waitlist>(school)
+        .map_or(Vec::new(), |w| w.0.iter().map(|&(_, p)| p).collect())
+}
+
+#[test]
+fn children_enrol_at_the_nearest_school() {
+    let mut app = app(None);
+    let near = school(&mut app, 0.0, 10);
+    let far = school(&mut app, 10.0, 10);
+    let child = person(&mut app, 2.0, 8);
+    let toddler = person(&mut app, 1.0, 3);
+    let adult = person(&mut app, 1.0, 30);
+    app.world_mut().entity_mut(child).insert(Unemployed);
+    app.update();
+
+    assert_eq!(pupils(&app, near), vec![child]);
+    assert!(pupils(&app, far).is_empty());
+    let world = app.world();
+    let emp = world.get::<Employment>(child).unwrap();
+    assert_eq!((emp.job, emp.role_index), (near, 0));
+    assert_eq!(world.get::<Employment>(child).unwrap().job, near);
+    assert!(world.get::<Unemployed>(child).is_none());
+    for other in [toddler, adult] {
+        assert!(world.get::<Employment>(other).is_none());
+    }
+    let events: Vec<Enrolled> = world
+        .resource::<Events<Enrolled>>()
+        .iter_current_update_events()
+        .copied()
+        .collect();
+    assert_eq!(
+        events,
+        vec![Enrolled {
+            person: child,
+            job: near,
+            role_index: 0
+        }]
+    );
+}
+
+#[test]
+fn full_schools_send_children_further() {
+    let mut app = app(None);
+    let near = school(&mut app, 0.0, 1);
+    let far = school(&mut app, 10.0, 5);
+    let first = person(&mut app, 1.0, 8);
+    let second = person(&mut app, 1.0, 9);
+    app.update();
+
+    assert_eq!(pupils(&app, near), vec![first]);
+    assert_eq!(pupils(&app, far), vec![second]);
+}
+
+#[test]
+fn waitlists_are_served_in_order() {
+    let mut app = app(None);
+    let near = school(&mut app, 0.0, 1);
+    let far = school(&mut app, 10.0, 1);
+    let kids: Vec<Entity> = (0..4).map(|i| person(&mut app, i as f32, 10)).collect();
+    app.update();
+
+    assert_eq!(pupils(&app, near), vec![kids[0]]);
+    assert_eq!(pupils(&app, far), vec![kids[1]]);
+    assert_eq!(waiting(&app, near), vec![kids[2], kids[3]]);
+    assert!(waiting(&app, far).is_empty());
+
+    // a later child joins the back of the queue
+    let late = person(&mut app, 0.0, 7);
+    app.update();
+    assert_eq!(waiting(&app, near), vec![kids[2], kids[3], late]);
+
+    app.world_mut().get_mut::<Job>(far).unwrap().roles[0].0.max = 2;
+    app.update();
+    assert_eq!(pupils(&app, far), vec![kids[1], kids[2]]);
+    assert_eq!(waiting(&app, near), vec![kids[3], late]);
+}
+
+const NURSERY: &str = r#"[(
+    name: "nursery",
+    roles: [(min: 0, max: 2, constraints: [AgeBetween(6, 18)], compulsory: true)],
+)]"#;
+
+#[test]
+fn long_waitlists_open_a_new_school() {
+    let dir = std::env::temp_dir().join(format!("simrs_enrolment_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("nursery.ron"), NURSERY).unwrap();
+
+    let mut app = app(Some(2));
+    app.add_plugins(JobCatalogPlugin::new(&dir).spawn("nursery", 1));
+    app.update();
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(
+        app.world()
+            .resource::<JobCatalog>()
+            .get("nursery")
+            .is_some()
+    );
+
+    let kids: Vec<Entity> = [0.0, 0.0, 4.0, 6.0]
+        .into_iter()
+        .map(|x| person(&mut app, x, 10))
+        .collect();
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let mut q = app.world_mut().query::<(Entity, &Job, &JobType)>();
+    let mut schools: Vec<(Entity, Vec<Entity>)> = q
+        .iter(app.world())
+        .map(|(e, job, _)| (e, job.roles[0].1.clone()))
+        .collect();
+    schools.sort();
+    assert_eq!(schools.len(), 2);
+    assert_eq!(schools[0].1, kids[..2]);
+    assert_eq!(schools[1].1, kids[2..]);
+    let site = app.world().get::<Position>(schools[1].0).unwrap();
+    assert_eq!(*site, Position::new(5.0, 0.0));
+}
+
+#[test]
+fn passes_run_on_the_interval() {
+    let mut app = app(None);
+    app.insert_resource(EnrolmentConfig {
+        open_at: None,
+        interval_secs: 10.0,
+    });
+    let advance = |app: &mut App, secs: u64| {
+        app.world_mut()
+            .resource_mut::<Time<Real>>()
+            .update_with_duration(Duration::from_secs(secs));
+    };
+    advance(&mut app, 0); // the first Real tick has no delta
+    let school = school(&mut app, 0.0, 5);
+    let first = person(&mut app, 0.0, 8);
+    app.update();
+    assert_eq!(pupils(&app, school), vec![first]);
+
+    let second = person(&mut app, 0.0, 8);
+    app.update();
+    assert_eq!(pupils(&app, school), vec![first]);
+
+    advance(&mut app, 10);
+    app.update();
+    assert_eq!(pupils(&app, school), vec![first, second]);
+}
+
+#[test]
+fn unknown_types_are_opened_once_defined() {
+    let mut app = app(Some(1));
+    app.init_resource::<JobCatalog>();
+    let full = school(&mut app, 0.0, 0);
+    app.world_mut()
+        .entity_mut(full)
+        .insert(JobType("nursery".into()));
+    person(&mut app, 0.0, 8);
+    let requests = |app: &App| {
+        app.world()
+            .resource::<Events<SpawnJob>>()
+            .iter_current_update_events()
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>()
+    };
+    app.update();
+    assert!(requests(&app).is_empty());
+
+    app.world_mut()
+        .resource_mut::<JobCatalog>()
+        .load_str("nursery.ron", NURSERY)
+        .unwrap();
+    app.update();
+    assert_eq!(requests(&app), vec!["nursery".to_string()]);
+}
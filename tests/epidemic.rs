@@ -0,0 +1,252 @@
+// Epidemic SEIR spread (what the tests mean)
+//
+// One real second is one simulated day.
+//
+//   (1) Household: parents and minor children infect each other; the source
+//       is credited with its secondary cases
+//   (2) Stages run E -> I -> R; leaving I clears HazardMultiplier and Absent,
+//       and waning immunity makes people susceptible again
+//   (3) Absent workers do not infect co-workers; present ones do
+//   (4) HazardMultiplier raises the mortality hazard
+//   (5) EpidemicStats: incidence and R-effective over the window, one
+//       history sample per window, and a bounded history that keeps its ends
+//   (6) Social: Graph<Tie> contacts are infected in proportion to tie
+//       strength, credited to the social channel
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Duration;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::epidemic::{Channel, EpidemicConfig, EpidemicPlugin, Infected, Infection, Stage};
+use simrs::jobs::{Absent, Job};
+use simrs::mortality::system::{MortalityTick, apply_mortality_with_rate};
+use simrs::mortality::{Death, HazardMultiplier};
+use simrs::person::{AgingConfig, Parents, Person};
+use simrs::graph::Graph;
+use simrs::records::{EPIDEMIC_HISTORY_MAX, EpidemicStats};
+use simrs::social::Tie;
+
+fn app(cfg: EpidemicConfig) -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig {
+        years_per_sec: 1.0 / 365.0,
+    });
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(9)));
+    app.insert_resource(cfg);
+    app.add_plugins(EpidemicPlugin);
+    step(&mut app, 0.0); // the first Real tick has no delta
+    app
+}
+
+fn step(app: &mut App, days: f64) {
+    app.world_mut()
+        .resource_mut::<Time<Real>>()
+        .update_with_duration(Duration::from_secs_f64(days));
+    app.update();
+}
+
+fn sick(days_left: f64) -> Infection {
+    Infection {
+        stage: Stage::Infectious,
+        days_left,
+        infected_by: None,
+        secondary: 0,
+    }
+}
+
+fn only(beta: [f64; 3]) -> EpidemicConfig {
+    EpidemicConfig {
+        latent_days: 0.0,
+        household_beta: beta[0],
+        workplace_beta: beta[1],
+        social_beta: beta[2],
+        absence_prob: 0.0,
+        ..Default::default()
+    }
+}
+
+fn infections(app: &mut App) -> Vec<Infected> {
+    let mut events = app.world_mut().resource_mut::<Events<Infected>>();
+    events.drain().collect()
+}
+
+#[test]
+fn households_infect_each_other() {
+    let mut app = app(only([1000.0, 0.0, 0.0]));
+    let world = app.world_mut();
+    let a = world.spawn((Person { age: 35.0 }, sick(10.0))).id();
+    let b = world.spawn(Person { age: 35.0 }).id();
+    let child = world.spawn((Person { age: 5.0 }, Parents(a, b))).id();
+    let grown = world.spawn((Person { age: 25.0 }, Parents(a, b))).id();
+    let stranger = world.spawn(Person { age: 35.0 }).id();
+
+    step(&mut app, 1.0);
+    let caught = infections(&mut app);
+    let mut who: Vec<Entity> = caught.iter().map(|i| i.entity).collect();
+    who.sort();
+    assert_eq!(who, vec![b, child]);
+    assert!(
+        caught
+            .iter()
+            .all(|i| i.by == Some(a) && i.channel == Channel::Household)
+    );
+    assert_eq!(app.world().get::<Infection>(a).unwrap().secondary, 2);
+    assert!(app.world().get::<Infection>(grown).is_none());
+    assert!(app.world().get::<Infection>(stranger).is_none());
+}
+
+#[test]
+fn stages_progress_and_immunity_wanes() {
+    let mut app = app(EpidemicConfig {
+        latent_days: 2.0,
+        infectious_days: 5.0,
+        immunity_days: Some(10.0),
+        absence_prob: 1.0,
+        initial_infections: 1,
+        ..Default::default()
+    });
+    let p = app.world_mut().spawn(Person { age: 30.0 }).id();
+    step(&mut app, 0.0); // seeded
+    assert_eq!(
+        app.world().get::<Infection>(p).unwrap().stage,
+        Stage::Exposed
+    );
+
+    let mut seen = vec![Stage::Exposed];
+    for _ in 0..2000 {
+        step(&mut app, 0.1);
+        let Some(i) = app.world().get::<Infection>(p) else {
+            break;
+        };
+        if *seen.last().unwrap() != i.stage {
+            seen.push(i.stage);
+            let ill = i.stage == Stage::Infectious;
+            assert_eq!(app.world().get::<HazardMultiplier>(p).is_some(), ill);
+            assert_eq!(app.world().get::<Absent>(p).is_some(), ill);
+        }
+    }
+    assert_eq!(
+        seen,
+        vec![Stage::Exposed, Stage::Infectious, Stage::Recovered]
+    );
+    assert!(app.world().get::<Infection>(p).is_none());
+}
+
+#[test]
+fn absent_workers_do_not_infect_colleagues() {
+    for (absence, expect) in [(1.0, false), (0.0, true)] {
+        let mut app = app(EpidemicConfig {
+            absence_prob: absence,
+            initial_infections: 1,
+            ..only([0.0, 1000.0, 0.0])
+        });
+        let world = app.world_mut();
+        let a = world.spawn(Person { age: 30.0 }).id();
+        let mut job = Job::builder().add_role(0, 5).build();
+        job.roles[0].1.push(a);
+        world.spawn(job);
+        step(&mut app, 0.0); // a is the only person, so a is seeded
+
+        let b = app.world_mut().spawn(Person { age: 30.0 }).id();
+        app.world_mut()
+            .query::<&mut Job>()
+            .single_mut(app.world_mut())
+            .unwrap()
+            .roles[0]
+            .1
+            .push(b);
+        step(&mut app, 0.5);
+        assert_eq!(app.world().get::<Infection>(b).is_some(), expect);
+    }
+}
+
+#[test]
+fn hazard_multiplier_raises_mortality() {
+    let mut world = World::new();
+    world.insert_resource(GameRNG(StdRng::seed_from_u64(2)));
+    world.init_resource::<MortalityTick>();
+    world.init_resource::<Events<Death>>();
+    for _ in 0..50 {
+        world.spawn(Person { age: 30.0 });
+        world.spawn((Person { age: 30.0 }, HazardMultiplier(1000.0)));
+    }
+    world
+        .run_system_once(apply_mortality_with_rate(0.0))
+        .unwrap();
+
+    let deaths: Vec<Entity> = world
+        .resource_mut::<Events<Death>>()
+        .drain()
+        .map(|d| d.entity)
+        .collect();
+    let ill = deaths
+        .iter()
+        .filter(|&&e| world.get::<HazardMultiplier>(e).is_some())
+        .count();
+    assert_eq!(ill, 50);
+    assert!(deaths.len() - ill < 10);
+}
+
+#[test]
+fn stats_track_incidence_and_r_effective() {
+    let mut stats = EpidemicStats::new(7.0);
+    stats.push_infection(0.0);
+    stats.push_infection(1.0);
+    stats.push_ended(1.0, 2);
+    stats.push_ended(2.0, 4);
+    stats.update(2.0, 0.1);
+    assert_eq!(stats.incidence.count(), 2);
+    assert_eq!(stats.r_effective, 3.0);
+    assert_eq!(stats.history.len(), 1);
+
+    stats.update(5.0, 0.1);
+    assert_eq!(stats.history.len(), 1); // within the same window
+
+    stats.update(9.5, 0.0);
+    assert_eq!(stats.incidence.count(), 0);
+    assert_eq!(stats.r_effective, 0.0);
+    assert_eq!(stats.history.len(), 2);
+    assert_eq!(stats.history[1].prevalence, 0.0);
+}
+
+#[test]
+fn stats_history_is_bounded() {
+    let mut stats = EpidemicStats::new(1.0);
+    let n = 3 * EPIDEMIC_HISTORY_MAX;
+    for t in 0..n {
+        stats.update(t as f64, 0.0);
+    }
+    assert!(stats.history.len() <= EPIDEMIC_HISTORY_MAX);
+    assert_eq!(stats.history.first().unwrap().t, 0.0);
+    assert_eq!(stats.history.last().unwrap().t, (n - 1) as f64);
+    assert!(stats.history.windows(2).all(|w| w[0].t < w[1].t));
+}
+
+#[test]
+fn social_contacts_are_infected_along_ties() {
+    let mut app = app(only([0.0, 0.0, 1000.0]));
+    let world = app.world_mut();
+    let friend = world.spawn(Person { age: 30.0 }).id();
+    let faint = world.spawn(Person { age: 30.0 }).id();
+    let stranger = world.spawn(Person { age: 30.0 }).id();
+    let mut ties = Graph::<Tie>::default();
+    ties.set(friend, Tie::friend(1.0));
+    ties.set(faint, Tie::friend(0.0));
+    let a = world.spawn((Person { age: 30.0 }, sick(10.0), ties)).id();
+
+    step(&mut app, 1.0);
+    let caught = infections(&mut app);
+    assert_eq!(caught.len(), 1);
+    assert_eq!(caught[0].entity, friend);
+    assert_eq!(caught[0].by, Some(a));
+    assert_eq!(caught[0].channel, Channel::Social);
+    assert_eq!(app.world().get::<Infection>(a).unwrap().secondary, 1);
+    assert!(app.world().get::<Infection>(faint).is_none());
+    assert!(app.world().get::<Infection>(stranger).is_none());
+}
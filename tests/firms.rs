@@ -0,0 +1,266 @@
+// Firms (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated year, stepped a month at a time;
+// payday every 30 days, firm reviews every 365.
+//
+//   (1) Founding a firm opens one job of its type, paid for from the firm's
+//       Account rather than one of the job's own
+//   (2) Output is sold as revenue, the sold items despawned, and wages
+//       booked as costs; a profitable firm adds seats to its full roles
+//   (3) A firm out of money and losing it closes: its jobs are despawned and
+//       their members laid off
+//   (4) Wages a firm cannot pay are still booked as costs, and any missed
+//       payroll closes the firm at its next review even if it got through
+//       the last one
+//   (5) Firms open more jobs while the population outgrows them, up to a cap
+//   (6) Without a JobCatalog no firm can be founded, and nothing panics
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::HashMap;
+
+use simrs::economy::{Account, Payer, PayrollConfig, PayrollPlugin};
+use simrs::firm::{Firm, FirmClosed, FirmConfig, FirmPlugin, Ledger};
+use simrs::hiring_manager::Unemployed;
+use simrs::inventory::component::Item;
+use simrs::jobs::{Employment, Job, JobCatalog, JobType};
+use simrs::person::{AgingConfig, Person};
+use simrs::personality::Personality;
+use simrs::production::ProductionPlugin;
+use simrs::separation::{Separated, SeparationReason};
+
+mod clock;
+use clock::step;
+
+const TYPES: &str = r#"[
+    (name: "bakery", roles: [(min: 1, max: 2, wage: 1000.0, produces: [(output: "bread", rate: 10.0)])]),
+    (name: "studio", roles: [(min: 1, max: 2, wage: 10000.0)]),
+]"#;
+
+#[derive(Resource, Default)]
+struct Log {
+    closed: Vec<FirmClosed>,
+    laid_off: Vec<Entity>,
+}
+
+fn app(cfg: FirmConfig, found: &str) -> App {
+    let mut catalog = JobCatalog::default();
+    catalog.load_str("types.ron", TYPES).unwrap();
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default())
+        .insert_resource(AgingConfig { years_per_sec: 1.0 })
+        .insert_resource(PayrollConfig {
+            period_days: 30.0,
+            initial_job_funds: 1e9,
+            initial_person_funds: 0.0,
+        })
+        .insert_resource(catalog)
+        .insert_resource(cfg)
+        .init_resource::<Log>()
+        .add_plugins(PayrollPlugin)
+        .add_plugins(ProductionPlugin)
+        .add_plugins(FirmPlugin::new().found(found))
+        .add_systems(
+            PostUpdate,
+            |mut closed: EventReader<FirmClosed>,
+             mut separated: EventReader<Separated>,
+             mut log: ResMut<Log>| {
+                log.closed.extend(closed.read().cloned());
+                log.laid_off.extend(
+                    separated
+                        .read()
+                        .filter(|s| s.reason == SeparationReason::LaidOff)
+                        .map(|s| s.person),
+                );
+            },
+        );
+    step(&mut app, 0.0);
+    app
+}
+
+fn config() -> FirmConfig {
+    FirmConfig {
+        review_days: 365.0,
+        prices: HashMap::from([("bread".to_string(), 200.0)]),
+        founding_funds: 5000.0,
+        seats_per_review: 1,
+        people_per_job: None,
+        max_jobs: 3,
+    }
+}
+
+fn months(app: &mut App, n: usize) {
+    for _ in 0..n {
+        step(app, 1.0 / 12.0 + 1e-6);
+    }
+}
+
+fn only_firm(app: &mut App) -> (Entity, Firm) {
+    let mut q = app.world_mut().query::<(Entity, &Firm)>();
+    let (e, f) = q.single(app.world()).unwrap();
+    (e, f.clone())
+}
+
+/// Seat `n` average people in the first role of `job`.
+fn staff(app: &mut App, job: Entity, n: usize) -> Vec<Entity> {
+    let world = app.world_mut();
+    let people: Vec<Entity> = (0..n)
+        .map(|_| {
+            world
+                .spawn((
+                    Person::new(),
+                    Personality::from_array([0.5; 6]),
+                    Employment::new(job, 0),
+                ))
+                .id()
+        })
+        .collect();
+    world.get_mut::<Job>(job).unwrap().roles[0].1 = people.clone();
+    people
+}
+
+#[test]
+fn founding_opens_a_job_paid_by_the_firm() {
+    let mut app = app(config(), "bakery");
+    step(&mut app, 0.0);
+    let (e, firm) = only_firm(&mut app);
+    assert_eq!(firm.job_type, "bakery");
+    assert_eq!(firm.jobs.len(), 1);
+    let world = app.world();
+    let job = firm.jobs[0];
+    assert_eq!(world.get::<JobType>(job).unwrap().0, "bakery");
+    assert_eq!(world.get::<Payer>(job), Some(&Payer(e)));
+    assert!(world.get::<Account>(job).is_none());
+    assert_eq!(world.get::<Account>(e).unwrap().balance, 5000.0);
+}
+
+#[test]
+fn profitable_firms_grow_full_roles() {
+    let mut app = app(config(), "bakery");
+    let (e, firm) = only_firm(&mut app);
+    let bakers = staff(&mut app, firm.jobs[0], 2);
+
+    months(&mut app, 6);
+    let wages = app.world().get::<Account>(bakers[0]).unwrap().balance;
+    assert!(wages > 0.0, "bakers are paid from the firm");
+    assert_eq!(
+        app.world().get::<Job>(firm.jobs[0]).unwrap().roles[0].0.max,
+        2
+    );
+
+    months(&mut app, 7);
+    // 2 bakers make 20 loaves a year for 4000, and cost 2000
+    let balance = app.world().get::<Account>(e).unwrap().balance;
+    assert!(balance > 6500.0 && balance < 7500.0, "balance {balance}");
+    // sold loaves leave the world; at most this month's are still held
+    let loaves = app.world_mut().query::<&Item>().iter(app.world()).count();
+    assert!(loaves <= 2, "{loaves} loaves left");
+    assert_eq!(
+        app.world().get::<Job>(firm.jobs[0]).unwrap().roles[0].0.max,
+        3
+    );
+}
+
+#[test]
+fn insolvent_firms_close() {
+    let mut app = app(
+        FirmConfig {
+            founding_funds: 1000.0,
+            ..config()
+        },
+        "studio",
+    );
+    let (e, firm) = only_firm(&mut app);
+    let staff = staff(&mut app, firm.jobs[0], 2);
+
+    months(&mut app, 13);
+    let world = app.world();
+    assert!(world.get_entity(e).is_err());
+    assert!(world.get_entity(firm.jobs[0]).is_err());
+    for &p in &staff {
+        assert!(world.get::<Unemployed>(p).is_some());
+        assert!(world.get::<Employment>(p).is_none());
+    }
+    let log = world.resource::<Log>();
+    assert_eq!(
+        log.closed,
+        vec![FirmClosed {
+            firm: e,
+            jobs: firm.jobs.clone()
+        }]
+    );
+    assert_eq!(log.laid_off, staff);
+}
+
+#[test]
+fn firms_that_run_dry_close_at_the_next_review() {
+    let mut app = app(
+        FirmConfig {
+            founding_funds: 12000.0,
+            ..config()
+        },
+        "studio",
+    );
+    let (e, firm) = only_firm(&mut app);
+    let staff = staff(&mut app, firm.jobs[0], 1);
+
+    // 12 paydays of ~822 leave ~2137 at the first review
+    months(&mut app, 12);
+    assert!(app.world().get_entity(e).is_ok());
+    assert!(app.world().resource::<Log>().closed.is_empty());
+
+    // 4 more paydays are owed ~3288, of which the firm can pay ~2137
+    months(&mut app, 4);
+    let world = app.world();
+    assert_eq!(world.get::<Account>(e).unwrap().balance, 0.0);
+    let &Ledger { costs, arrears, .. } = world.get::<Ledger>(e).unwrap();
+    assert!((costs - 3288.0).abs() < 1.0, "costs {costs}");
+    assert!((arrears - 1151.0).abs() < 1.0, "arrears {arrears}");
+
+    months(&mut app, 8);
+    let world = app.world();
+    assert!(world.get_entity(e).is_err());
+    assert!(world.get::<Employment>(staff[0]).is_none());
+    let log = world.resource::<Log>();
+    assert_eq!(log.closed.len(), 1);
+    assert_eq!(log.laid_off, staff);
+}
+
+#[test]
+fn growing_populations_get_more_jobs() {
+    let mut app = app(
+        FirmConfig {
+            people_per_job: Some(2.0),
+            ..config()
+        },
+        "bakery",
+    );
+    for _ in 0..5 {
+        app.world_mut().spawn(Person::new());
+    }
+    months(&mut app, 13);
+    let (e, firm) = only_firm(&mut app);
+    assert_eq!(firm.jobs.len(), 2);
+    assert_eq!(app.world().get::<Payer>(firm.jobs[1]), Some(&Payer(e)));
+
+    for _ in 0..20 {
+        app.world_mut().spawn(Person::new());
+    }
+    months(&mut app, 24);
+    assert_eq!(only_firm(&mut app).1.jobs.len(), 3);
+}
+
+#[test]
+fn no_catalog_founds_no_firms() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default())
+        .insert_resource(AgingConfig { years_per_sec: 1.0 })
+        .insert_resource(config())
+        .add_plugins(FirmPlugin::new().found("bakery"));
+    step(&mut app, 0.0);
+    months(&mut app, 13);
+
+    let firms = app.world_mut().query::<&Firm>().iter(app.world()).count();
+    assert_eq!(firms, 0);
+}
@@ -0,0 +1,140 @@
+// Graph export to GraphML and DOT (what the tests mean)
+//
+// Fixture: three people a — b — c on an undirected Graph<f32>, with b employed
+// and a carrying a personality; d is a bare entity pointed at by c.
+//
+//   (1) Undirected networks list each mirrored edge once, with its weight
+//   (2) Node attributes come from the caller; person_attributes gives age,
+//       traits and employment where present
+//   (3) GraphML declares every attribute key with a type
+//   (4) DOT uses `graph`/`--` for undirected and `digraph`/`->` for directed
+//   (5) ExportGraph<V> writes the requested file; the interval writes both formats
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::time::Duration;
+
+use simrs::graph::{
+    AttrValue, ExportGraph, Graph, GraphExport, GraphExportPlugin, GraphFormat, RelationshipPlugin,
+};
+use simrs::jobs::Employment;
+use simrs::person::Person;
+use simrs::personality::Personality;
+use simrs::social::person_attributes;
+
+fn populate(world: &mut World) -> [Entity; 4] {
+    let job = world.spawn_empty().id();
+    let a = world
+        .spawn((Person { age: 30.0 }, Personality::from_array([0.5; 6])))
+        .id();
+    let b = world
+        .spawn((Person { age: 40.0 }, Employment::new(job, 1)))
+        .id();
+    let c = world.spawn(Person { age: 50.0 }).id();
+    let d = world.spawn_empty().id();
+    let mut ga = Graph::<f32>::default();
+    ga.set(b, 0.5);
+    let mut gb = Graph::<f32>::default();
+    gb.set(a, 0.5);
+    gb.set(c, 0.25);
+    let mut gc = Graph::<f32>::default();
+    gc.set(b, 0.25);
+    gc.set(d, 1.0);
+    world.entity_mut(a).insert(ga);
+    world.entity_mut(b).insert(gb);
+    world.entity_mut(c).insert(gc);
+    [a, b, c, d]
+}
+
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("simrs_{name}_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn snapshot_dedups_and_collects_attributes() {
+    let mut app = App::new();
+    app.add_plugins(RelationshipPlugin::<f32>::undirected());
+    let [a, b, c, d] = populate(app.world_mut());
+    let export = GraphExport::from_world::<f32>(app.world_mut(), person_attributes);
+
+    assert!(!export.directed);
+    assert_eq!(export.nodes.len(), 4);
+    // a-b, b-c once each; c-d only one way
+    assert_eq!(export.edges, vec![(a, b, 0.5), (b, c, 0.25), (c, d, 1.0)]);
+
+    let attrs = |e: Entity| &export.nodes.iter().find(|(n, _)| *n == e).unwrap().1;
+    assert_eq!(attrs(a)["openness"], AttrValue::Float(0.5));
+    assert_eq!(attrs(a)["employed"], AttrValue::Bool(false));
+    assert_eq!(attrs(b)["employed"], AttrValue::Bool(true));
+    assert_eq!(attrs(b)["role"], AttrValue::Int(1));
+    assert_eq!(attrs(c)["age"], AttrValue::Float(50.0));
+    assert!(attrs(d).is_empty());
+}
+
+#[test]
+fn formats_render_structure() {
+    let mut world = World::new();
+    let [a, b, ..] = populate(&mut world);
+    let export = GraphExport::from_world::<f32>(&mut world, person_attributes);
+    assert!(export.directed); // no RelationshipConfig: treat as directed
+    assert_eq!(export.edges.len(), 5);
+
+    let xml = export.to_graphml();
+    assert!(xml.contains("edgedefault=\"directed\""));
+    assert!(xml.contains("<key id=\"age\" for=\"node\" attr.name=\"age\" attr.type=\"double\"/>"));
+    assert!(xml.contains(
+        "<key id=\"employed\" for=\"node\" attr.name=\"employed\" attr.type=\"boolean\"/>"
+    ));
+    assert_eq!(xml.matches("<edge ").count(), 5);
+    assert_eq!(xml.matches("<node ").count(), 4);
+
+    let dot = export.to_dot();
+    assert!(dot.starts_with("digraph G {"));
+    assert!(dot.contains(&format!(
+        "n{} -> n{} [weight=0.5];",
+        a.to_bits(),
+        b.to_bits()
+    )));
+
+    let undirected = GraphExport {
+        directed: false,
+        ..export
+    };
+    let dot = undirected.to_dot();
+    assert!(dot.starts_with("graph G {") && dot.contains(" -- "));
+}
+
+#[test]
+fn plugin_exports_on_request_and_interval() {
+    let dir = temp_dir("export");
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(RelationshipPlugin::<f32>::undirected());
+    app.add_plugins(
+        GraphExportPlugin::<f32>::new(&dir, "net")
+            .every(10.0)
+            .attributes(person_attributes),
+    );
+    populate(app.world_mut());
+
+    app.update(); // first run exports once
+    let files = |dir: &std::path::Path| std::fs::read_dir(dir).map_or(0, |d| d.count());
+    assert_eq!(files(&dir), 2);
+
+    app.world_mut()
+        .resource_mut::<Time<Real>>()
+        .update_with_duration(Duration::from_secs(1));
+    let on_demand = dir.join("manual.graphml");
+    app.world_mut()
+        .send_event(ExportGraph::<f32>::new(&on_demand, GraphFormat::GraphMl));
+    app.update();
+    assert_eq!(files(&dir), 3); // not yet due again
+    let xml = std::fs::read_to_string(&on_demand).unwrap();
+    assert!(xml.contains("edgedefault=\"undirected\""));
+    assert!(xml.contains("attr.name=\"age\""));
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,100 @@
+// Graph algorithms over Graph<V> (what the tests mean)
+//
+// Fixture: two triangles {a,b,c} and {d,e,f} joined by the bridge c — d,
+// plus an isolated graph owner g. All edges are symmetric.
+//
+//   (1) Every Graph<V> owner and target becomes a node
+//   (2) BFS/DFS from a reach the six linked nodes and nothing else
+//   (3) Dijkstra prefers the cheaper detour over the direct heavy edge
+//   (4) Components: the two triangles form one, g is alone
+//   (5) The bridge endpoints have the highest betweenness
+//   (6) Label propagation separates the two triangles
+
+use bevy_ecs::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::graph::{Graph, Network};
+
+struct Fixture {
+    world: World,
+    n: [Entity; 7],
+}
+
+fn link(world: &mut World, a: Entity, b: Entity, w: f64) {
+    world.get_mut::<Graph<f64>>(a).unwrap().set(b, w);
+    world.get_mut::<Graph<f64>>(b).unwrap().set(a, w);
+}
+
+fn fixture() -> Fixture {
+    let mut world = World::new();
+    let n: [Entity; 7] = std::array::from_fn(|_| world.spawn(Graph::<f64>::default()).id());
+    let [a, b, c, d, e, f, _g] = n;
+    for (x, y) in [(a, b), (b, c), (a, c), (d, e), (e, f), (d, f), (c, d)] {
+        link(&mut world, x, y, 1.0);
+    }
+    Fixture { world, n }
+}
+
+#[test]
+fn network_covers_owners_and_traversals() {
+    let Fixture { mut world, n } = fixture();
+    let net = Network::from_world::<f64>(&mut world);
+    assert_eq!(net.len(), 7);
+
+    let [a, .., g] = n;
+    let mut reached = net.bfs(a);
+    assert_eq!(reached[0], a);
+    reached.sort();
+    assert_eq!(reached, n[..6].to_vec());
+    assert_eq!(net.dfs(a).len(), 6);
+    assert_eq!(net.bfs(g), vec![g]);
+}
+
+#[test]
+fn shortest_path_uses_weights_as_costs() {
+    let Fixture { mut world, n } = fixture();
+    let [a, b, c, ..] = n;
+    link(&mut world, a, c, 5.0); // make the direct edge expensive
+    let net = Network::from_world::<f64>(&mut world);
+
+    let (cost, path) = net.shortest_path(a, c).unwrap();
+    assert_eq!(path, vec![a, b, c]);
+    assert_eq!(cost, 2.0);
+    assert!(net.shortest_path(a, n[6]).is_none());
+}
+
+#[test]
+fn components_and_centrality() {
+    let Fixture { mut world, n } = fixture();
+    let [_, _, c, d, .., g] = n;
+    let net = Network::from_world::<f64>(&mut world);
+
+    let comps = net.connected_components();
+    assert_eq!(comps.len(), 2);
+    assert_eq!(comps[0], n[..6].to_vec());
+    assert_eq!(comps[1], vec![g]);
+
+    let degree = net.degree_centrality();
+    assert_eq!(degree[&c], 3.0 / 6.0);
+    assert_eq!(degree[&g], 0.0);
+
+    let between = net.betweenness_centrality();
+    let top = between.values().copied().fold(0.0, f64::max);
+    assert_eq!(between[&c], top);
+    assert_eq!(between[&d], top);
+}
+
+#[test]
+fn label_propagation_splits_triangles() {
+    let Fixture { mut world, n } = fixture();
+    let [a, b, c, d, e, f, _] = n;
+    let net = Network::from_world::<f64>(&mut world);
+    let labels = net.label_propagation(50, &mut StdRng::seed_from_u64(4));
+
+    assert_eq!(labels[&a], labels[&b]);
+    assert_eq!(labels[&b], labels[&c]);
+    assert_eq!(labels[&d], labels[&e]);
+    assert_eq!(labels[&e], labels[&f]);
+    assert_ne!(labels[&a], labels[&f]);
+}
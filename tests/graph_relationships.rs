@@ -0,0 +1,111 @@
+// Relationship plugin over Graph<V> (what the tests mean)
+//
+//   (1) Undirected: adding a -> b creates b -> a with the same value
+//   (2) Undirected: value updates and removals are mirrored
+//   (3) Despawning an endpoint removes every edge that pointed at it
+//   (4) Directed mode never touches the other endpoint
+//   (5) Every edge appearing/disappearing is reported as an event
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+
+use simrs::graph::{EdgeAdded, EdgeRemoved, Graph, RelationshipPlugin};
+
+fn app(plugin: RelationshipPlugin<f32>) -> App {
+    let mut app = App::new();
+    app.add_plugins(plugin);
+    app
+}
+
+fn edge(app: &App, from: Entity, to: Entity) -> Option<f32> {
+    app.world().get::<Graph<f32>>(from)?.get(&to).copied()
+}
+
+fn added(app: &App) -> Vec<(Entity, Entity)> {
+    let ev = app.world().resource::<Events<EdgeAdded<f32>>>();
+    let mut v: Vec<_> = ev.iter_current_update_events().map(|e| (e.from, e.to)).collect();
+    v.sort();
+    v
+}
+
+fn removed(app: &App) -> Vec<(Entity, Entity)> {
+    let ev = app.world().resource::<Events<EdgeRemoved<f32>>>();
+    let mut v: Vec<_> = ev.iter_current_update_events().map(|e| (e.from, e.to)).collect();
+    v.sort();
+    v
+}
+
+#[test]
+fn undirected_edges_are_mirrored() {
+    let mut app = app(RelationshipPlugin::undirected());
+    let b = app.world_mut().spawn_empty().id();
+    let mut g = Graph::<f32>::default();
+    g.set(b, 0.5);
+    let a = app.world_mut().spawn(g).id();
+    app.update();
+    let mut expect = vec![(a, b), (b, a)];
+    expect.sort();
+    assert_eq!(added(&app), expect);
+
+    app.update(); // mirror onto a graph-less entity lands via commands
+    assert_eq!(edge(&app, b, a), Some(0.5));
+    assert!(added(&app).is_empty());
+
+    app.world_mut().get_mut::<Graph<f32>>(a).unwrap().set(b, 0.9);
+    app.update();
+    assert_eq!(edge(&app, b, a), Some(0.9));
+
+    app.world_mut().get_mut::<Graph<f32>>(b).unwrap().remove(&a);
+    app.update();
+    assert_eq!(edge(&app, a, b), None);
+    assert_eq!(removed(&app), expect);
+}
+
+#[test]
+fn despawn_removes_incoming_edges() {
+    let mut app = app(RelationshipPlugin::directed());
+    let world = app.world_mut();
+    let target = world.spawn_empty().id();
+    let other = world.spawn_empty().id();
+    let mut g = Graph::<f32>::default();
+    g.set(target, 1.0);
+    g.set(other, 2.0);
+    let a = world.spawn(g).id();
+    app.update();
+
+    app.world_mut().despawn(target);
+    app.update();
+
+    assert_eq!(edge(&app, a, target), None);
+    assert_eq!(edge(&app, a, other), Some(2.0));
+    assert_eq!(removed(&app), vec![(a, target)]);
+}
+
+#[test]
+fn despawned_node_in_undirected_graph_leaves_no_dangling_edges() {
+    let mut app = app(RelationshipPlugin::undirected());
+    let world = app.world_mut();
+    let a = world.spawn(Graph::<f32>::default()).id();
+    let b = world.spawn(Graph::<f32>::default()).id();
+    app.world_mut().get_mut::<Graph<f32>>(a).unwrap().set(b, 1.0);
+    app.update();
+    assert_eq!(edge(&app, b, a), Some(1.0));
+
+    app.world_mut().despawn(b);
+    app.update();
+    assert_eq!(app.world().get::<Graph<f32>>(a).unwrap().degree(), 0);
+}
+
+#[test]
+fn directed_mode_does_not_mirror() {
+    let mut app = app(RelationshipPlugin::directed());
+    let world = app.world_mut();
+    let b = world.spawn(Graph::<f32>::default()).id();
+    let mut g = Graph::<f32>::default();
+    g.set(b, 1.0);
+    let a = world.spawn(g).id();
+    app.update();
+
+    assert_eq!(edge(&app, b, a), None);
+    assert_eq!(added(&app), vec![(a, b)]);
+}
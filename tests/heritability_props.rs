@@ -0,0 +1,122 @@
+// Heritable personality (what the tests mean)
+//
+//   (1) h² = 1 with no noise: child equals the mid-parent
+//   (2) h² = 0: child is independent of the parents (population mean)
+//   (3) Variance is preserved from one generation to the next under random mating
+//   (4) ECS: a person with `Parents` inherits instead of drawing fresh
+//   (5) Cohort stats: mean/variance match a hand computation
+
+use bevy_app::prelude::*;
+use bevy_time::{Real, Time};
+use approx::assert_abs_diff_eq;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::person::{Parents, Person};
+use simrs::personality::{
+    inherit_personality, sample_personality, Personality, PersonalityConfig, PersonalityPlugin,
+};
+use simrs::records::CohortStats;
+
+fn unclamped() -> PersonalityConfig {
+    PersonalityConfig {
+        min: f64::NEG_INFINITY,
+        max: f64::INFINITY,
+        ..Default::default()
+    }
+}
+
+fn variance(xs: &[f64]) -> f64 {
+    let n = xs.len() as f64;
+    let m = xs.iter().sum::<f64>() / n;
+    xs.iter().map(|x| (x - m).powi(2)).sum::<f64>() / n
+}
+
+#[test]
+fn full_heritability_without_noise_gives_midparent() {
+    let cfg = PersonalityConfig {
+        heritability: [1.0; 6],
+        inheritance_noise: 0.0,
+        ..unclamped()
+    };
+    let l = cfg.covariance_factor().unwrap();
+    let a = Personality::from_array([0.2, 0.4, 0.6, 0.8, 0.1, 0.9]);
+    let b = Personality::from_array([0.4, 0.6, 0.2, 0.8, 0.3, 0.5]);
+    let child = inherit_personality(&cfg, &l, &a, &b, &mut StdRng::seed_from_u64(3));
+    for (c, (x, y)) in child.as_array().iter().zip(a.as_array().iter().zip(b.as_array())) {
+        assert_abs_diff_eq!(*c, 0.5 * (x + y), epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn zero_heritability_ignores_parents() {
+    let cfg = PersonalityConfig {
+        heritability: [0.0; 6],
+        ..unclamped()
+    };
+    let l = cfg.covariance_factor().unwrap();
+    let extreme = Personality::from_array([5.0; 6]);
+    let mut rng = StdRng::seed_from_u64(11);
+    let n = 20_000;
+    let mean_o = (0..n)
+        .map(|_| inherit_personality(&cfg, &l, &extreme, &extreme, &mut rng).openness)
+        .sum::<f64>()
+        / n as f64;
+    assert_abs_diff_eq!(mean_o, cfg.means[0], epsilon = 0.01);
+}
+
+#[test]
+fn variance_is_stable_across_a_generation() {
+    let cfg = unclamped();
+    let l = cfg.covariance_factor().unwrap();
+    let mut rng = StdRng::seed_from_u64(5);
+    let parents: Vec<_> = (0..40_000).map(|_| sample_personality(&cfg, &l, &mut rng)).collect();
+    let children: Vec<_> = parents
+        .chunks(2)
+        .map(|pair| inherit_personality(&cfg, &l, &pair[0], &pair[1], &mut rng))
+        .collect();
+    let parent_n: Vec<f64> = parents.iter().map(|p| p.neuroticism).collect();
+    let child_n: Vec<f64> = children.iter().map(|p| p.neuroticism).collect();
+    assert_abs_diff_eq!(variance(&child_n), variance(&parent_n), epsilon = 0.002);
+}
+
+#[test]
+fn newborn_with_parents_inherits() {
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(1)));
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(PersonalityConfig {
+        heritability: [1.0; 6],
+        inheritance_noise: 0.0,
+        ..Default::default()
+    });
+    app.add_plugins(PersonalityPlugin);
+
+    let traits = Personality::from_array([0.9, 0.1, 0.9, 0.1, 0.9, 0.1]);
+    let world = app.world_mut();
+    let a = world.spawn((Person { age: 30.0 }, traits)).id();
+    let b = world.spawn((Person { age: 32.0 }, traits)).id();
+    let child = world.spawn((Person::new(), Parents(a, b))).id();
+    app.update();
+
+    let got = app.world().get::<Personality>(child).unwrap().as_array();
+    for (g, t) in got.iter().zip(traits.as_array()) {
+        assert_abs_diff_eq!(*g, t, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn cohort_stats_bucket_by_window() {
+    let mut stats = CohortStats::new(10.0);
+    stats.push(1.0, [1.0; 6]);
+    stats.push(9.0, [3.0; 6]);
+    stats.push(12.0, [7.0; 6]);
+
+    let first = stats.get(0).unwrap();
+    assert_eq!(first.count, 2);
+    assert_abs_diff_eq!(first.mean()[0], 2.0);
+    assert_abs_diff_eq!(first.variance()[0], 1.0);
+    assert_eq!(stats.get(1).unwrap().count, 1);
+    assert_eq!(stats.iter().map(|(c, _)| c).collect::<Vec<_>>(), vec![0, 1]);
+}
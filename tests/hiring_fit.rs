@@ -0,0 +1,87 @@
+// Personality-based hiring (what the tests mean)
+//
+//   (1) TraitProfile::fit is 1 on target and falls with weighted distance
+//   (2) With more applicants than seats, the best fits are hired
+//   (3) The hire carries an Employment with the fit score
+//   (4) Roles without a profile keep first-come order and record no fit
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Employment, Job};
+use simrs::personality::{Personality, Trait};
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+fn app_with_hiring() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8));
+    app
+}
+
+fn applicant(world: &mut World, conscientiousness: f64) -> Entity {
+    let mut p = Personality::from_array([0.5; 6]);
+    p.conscientiousness = conscientiousness;
+    world.spawn((Age { years: 30 }, Unemployed, p)).id()
+}
+
+#[test]
+fn profile_fit_is_weighted_distance() {
+    let job = Job::builder()
+        .add_role(1, 1)
+        .prefers(Trait::Conscientiousness, 0.8, 3.0)
+        .prefers(Trait::Neuroticism, 0.2, 1.0)
+        .build();
+    let profile = job.roles[0].0.desired.clone().unwrap();
+
+    let mut p = Personality::from_array([0.5; 6]);
+    p.conscientiousness = 0.8;
+    p.neuroticism = 0.2;
+    assert_abs_diff_eq!(profile.fit(&p), 1.0);
+    p.conscientiousness = 0.4;
+    assert_abs_diff_eq!(profile.fit(&p), 1.0 - 3.0 * 0.4 / 4.0, epsilon = 1e-12);
+}
+
+#[test]
+fn best_fitting_applicants_are_hired() {
+    let mut app = app_with_hiring();
+    let world = app.world_mut();
+    let job = world
+        .spawn(
+            Job::builder()
+                .add_role(2, 2)
+                .age_gte(18)
+                .prefers(Trait::Conscientiousness, 0.9, 1.0)
+                .build(),
+        )
+        .id();
+    let low = applicant(world, 0.1);
+    let best = applicant(world, 0.9);
+    let good = applicant(world, 0.7);
+    app.update();
+
+    let members = app.world().get::<Job>(job).unwrap().roles[0].1.clone();
+    assert_eq!(members, vec![best, good]);
+    assert!(app.world().get::<Unemployed>(low).is_some());
+
+    let m = app.world().get::<Employment>(best).unwrap();
+    assert_eq!((m.job, m.role_index), (job, 0));
+    assert_abs_diff_eq!(m.fit.unwrap(), 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn roles_without_profile_hire_in_inbox_order() {
+    let mut app = app_with_hiring();
+    let world = app.world_mut();
+    let job = world.spawn(Job::builder().add_role(1, 1).age_gte(18).build()).id();
+    let first = applicant(world, 0.1);
+    let _second = applicant(world, 0.9);
+    app.update();
+
+    assert_eq!(app.world().get::<Job>(job).unwrap().roles[0].1, vec![first]);
+    assert!(app.world().get::<Employment>(first).unwrap().fit.is_none());
+}
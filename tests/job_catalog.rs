@@ -0,0 +1,241 @@
+// Job types from files (what the tests mean)
+//
+//   (1) A RON definition becomes a template with seats, wages, constraints
+//       and a trait profile, and can be instantiated any number of times
+//   (2) Syntax errors point at line and column; invalid definitions list
+//       every problem, and leave the catalog as it was
+//   (3) A name can only be defined by one file; reloading a file replaces it
+//   (4) JobCatalogPlugin spawns instances at startup and hot reloads edits
+//       into live jobs, releasing members whose seats disappeared
+//   (5) Reloaded roles are matched by name: members follow their role when
+//       it moves, and are laid off when its new constraints reject them
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::time::{Duration, SystemTime};
+
+use simrs::hiring_manager::Unemployed;
+use simrs::jobs::{
+    CatalogError, Constraint, Employment, Job, JobCatalog, JobCatalogPlugin, JobType,
+};
+use simrs::person::Person;
+use simrs::personality::Trait;
+use simrs::separation::{Separated, SeparationPlugin, SeparationReason};
+
+const SHOP: &str = r#"[
+    (
+        name: "shop",
+        roles: [
+            (name: "clerk", min: 1, max: 3, wage: 20000.0,
+             constraints: [AgeAtLeast(16), Or([MinEducation(10.0), Has("Skills")])],
+             prefers: [(Agreeableness, 0.8, 2.0)]),
+            (min: 0, max: 1, wage: 45000.0),
+        ],
+    ),
+]"#;
+
+#[test]
+fn definitions_become_reusable_templates() {
+    let mut catalog = JobCatalog::default();
+    assert_eq!(catalog.load_str("shop.ron", SHOP).unwrap(), vec!["shop"]);
+
+    let a = catalog.instantiate("shop").unwrap();
+    let b = catalog.instantiate("shop").unwrap();
+    assert!(catalog.instantiate("bakery").is_none());
+    for job in [&a, &b] {
+        assert_eq!(job.roles.len(), 2);
+        assert!(job.roles.iter().all(|(_, members)| members.is_empty()));
+    }
+    let (clerk, _) = &a.roles[0];
+    assert_eq!((clerk.min, clerk.max, clerk.wage), (1, 3, 20000.0));
+    assert!(matches!(clerk.constraints[0], Constraint::AgeAtLeast(16)));
+    assert_eq!(
+        clerk.constraints[1].to_string(),
+        "(education >= 10 years or has Skills)"
+    );
+    let profile = clerk.desired.as_ref().unwrap();
+    assert_eq!(profile.targets, vec![(Trait::Agreeableness, 0.8, 2.0)]);
+    assert_eq!(a.roles[1].0.wage, 45000.0);
+    assert!(a.roles[1].0.desired.is_none());
+}
+
+#[test]
+fn errors_are_specific_and_atomic() {
+    let mut catalog = JobCatalog::default();
+    catalog.load_str("shop.ron", SHOP).unwrap();
+
+    let err = catalog
+        .load_str(
+            "shop.ron",
+            "[\n  (name: \"shop\", roles: [(min: 1, max: 2, pay: 3)]),\n]",
+        )
+        .unwrap_err();
+    let CatalogError::Parse { line, .. } = &err else {
+        panic!("expected a parse error, got {err}");
+    };
+    assert_eq!(*line, 2);
+    assert!(err.to_string().starts_with("shop.ron:2:"), "{err}");
+
+    let bad = r#"[
+        (name: "shop", roles: [
+            (name: "clerk", min: 4, max: 2, constraints: [AgeBetween(30, 20), Has("Wings")]),
+        ]),
+        (name: "empty", roles: []),
+    ]"#;
+    let err = catalog.load_str("shop.ron", bad).unwrap_err();
+    let CatalogError::Invalid { problems, .. } = &err else {
+        panic!("expected validation errors, got {err}");
+    };
+    assert_eq!(problems.len(), 4, "{err}");
+    assert!(problems[0].contains("shop/clerk: min 4 exceeds max 2"));
+    assert!(problems[1].contains("AgeBetween(30, 20) admits no age"));
+    assert!(problems[2].contains("unknown component \"Wings\""));
+    assert!(problems[3].contains("empty: has no roles"));
+
+    // the old definition survives
+    assert_eq!(catalog.get("shop").unwrap().roles[0].max, 3);
+}
+
+#[test]
+fn one_file_owns_each_name() {
+    let mut catalog = JobCatalog::default();
+    catalog.load_str("a.ron", SHOP).unwrap();
+    let err = catalog.load_str("b.ron", SHOP).unwrap_err();
+    assert!(
+        err.to_string().contains("shop: already defined in a.ron"),
+        "{err}"
+    );
+
+    let renamed = SHOP.replace("\"shop\"", "\"store\"");
+    catalog.load_str("a.ron", &renamed).unwrap();
+    assert_eq!(catalog.names().collect::<Vec<_>>(), vec!["store"]);
+}
+
+#[test]
+fn plugin_spawns_and_hot_reloads() {
+    let dir = std::env::temp_dir().join(format!("simrs_jobs_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("shop.ron");
+    std::fs::write(&file, SHOP).unwrap();
+
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(JobCatalogPlugin::new(&dir).spawn("shop", 2).hot_reload(0.0))
+        .add_plugins(SeparationPlugin); // lays off the members a reload unseats
+    app.update();
+
+    let mut q = app.world_mut().query::<(Entity, &Job, &JobType)>();
+    let shops: Vec<Entity> = q.iter(app.world()).map(|(e, _, _)| e).collect();
+    assert_eq!(shops.len(), 2);
+    assert!(q.iter(app.world()).all(|(_, _, t)| t.0 == "shop"));
+
+    // seat a manager and three clerks in the first shop
+    let world = app.world_mut();
+    let people: Vec<Entity> = (0..4)
+        .map(|i| {
+            world
+                .spawn(Employment::new(shops[0], (i == 3) as usize))
+                .id()
+        })
+        .collect();
+    {
+        let mut job = world.get_mut::<Job>(shops[0]).unwrap();
+        job.roles[0].1.extend(&people[..3]);
+        job.roles[1].1.push(people[3]);
+    }
+
+    // one role left, and only two clerk seats
+    let edited = r#"[(name: "shop", roles: [(name: "clerk", min: 1, max: 2, wage: 25000.0)])]"#;
+    rewrite(&file, edited);
+    app.update();
+
+    let world = app.world();
+    let job = world.get::<Job>(shops[0]).unwrap();
+    assert_eq!(job.roles.len(), 1);
+    assert_eq!(job.roles[0].0.wage, 25000.0);
+    assert_eq!(job.roles[0].1, people[..2]);
+    for &released in &people[2..] {
+        assert!(world.get::<Unemployed>(released).is_some());
+        assert!(world.get::<Employment>(released).is_none());
+    }
+    let laid_off: Vec<Entity> = world
+        .resource::<Events<Separated>>()
+        .iter_current_update_events()
+        .filter(|s| s.reason == SeparationReason::LaidOff)
+        .map(|s| s.person)
+        .collect();
+    assert_eq!(laid_off.len(), 2);
+    assert_eq!(world.get::<Job>(shops[1]).unwrap().roles[0].0.max, 2);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn reloaded_roles_match_by_name() {
+    let dir = std::env::temp_dir().join(format!("simrs_roles_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("shop.ron");
+    let named = r#"[(name: "shop", roles: [
+        (name: "clerk", min: 1, max: 3, constraints: [AgeAtLeast(16)]),
+        (name: "manager", min: 0, max: 1),
+    ])]"#;
+    std::fs::write(&file, named).unwrap();
+
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(JobCatalogPlugin::new(&dir).spawn("shop", 1).hot_reload(0.0))
+        .add_plugins(SeparationPlugin);
+    app.update();
+    let shop = app
+        .world_mut()
+        .query_filtered::<Entity, With<Job>>()
+        .single(app.world())
+        .unwrap();
+
+    // an adult and a minor clerk, and a manager
+    let world = app.world_mut();
+    let adult = world
+        .spawn((Person { age: 30.0 }, Employment::new(shop, 0)))
+        .id();
+    let minor = world
+        .spawn((Person { age: 16.0 }, Employment::new(shop, 0)))
+        .id();
+    let manager = world.spawn(Employment::new(shop, 1)).id();
+    {
+        let mut job = world.get_mut::<Job>(shop).unwrap();
+        job.roles[0].1.extend([adult, minor]);
+        job.roles[1].1.push(manager);
+    }
+
+    // the manager role moves to the front; clerks must now be adults
+    let edited = r#"[(name: "shop", roles: [
+        (name: "manager", min: 0, max: 1),
+        (name: "clerk", min: 1, max: 3, constraints: [AgeAtLeast(18)]),
+    ])]"#;
+    rewrite(&file, edited);
+    app.update();
+
+    let world = app.world();
+    let job = world.get::<Job>(shop).unwrap();
+    assert_eq!(job.roles[0].1, [manager]);
+    assert_eq!(job.roles[1].1, [adult]);
+    assert_eq!(world.get::<Employment>(manager).unwrap().role_index, 0);
+    assert_eq!(world.get::<Employment>(adult).unwrap().role_index, 1);
+    assert!(world.get::<Employment>(minor).is_none());
+    assert!(world.get::<Unemployed>(minor).is_some());
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+/// Replace a job file and push its mtime forward so the reload sees it.
+fn rewrite(file: &std::path::Path, text: &str) {
+    std::fs::write(file, text).unwrap();
+    let later = SystemTime::now() + Duration::from_secs(10);
+    std::fs::File::options()
+        .write(true)
+        .open(file)
+        .unwrap()
+        .set_modified(later)
+        .unwrap();
+}
@@ -0,0 +1,160 @@
+// Role constraint language (what the tests mean)
+//
+//   (1) Leaf constraints read age (Person, else Age), traits, education,
+//       skills, sex, residence and component presence
+//   (2) And/Or/Not combine them; failures explain every unmet part
+//   (3) Missing data fails with a reason instead of passing silently
+//   (4) Hiring only considers applicants the role admits
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::HashMap;
+
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Constraint, Job, check_all};
+use simrs::person::{Education, Person, Position, Sex, Skills};
+use simrs::personality::{Personality, Trait};
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+fn candidate(world: &mut World) -> Entity {
+    let mut p = Personality::from_array([0.5; 6]);
+    p.openness = 0.8;
+    world
+        .spawn((
+            Person { age: 30.0 },
+            p,
+            Education { years: 12.0 },
+            Skills(HashMap::from([("carpentry".to_string(), 0.6)])),
+            Sex::Female,
+            Position::new(3.0, 4.0),
+        ))
+        .id()
+}
+
+fn reasons(world: &World, e: Entity, cs: &[Constraint]) -> Vec<String> {
+    check_all(cs, &world.entity(e))
+        .err()
+        .map_or(Vec::new(), |r| r.reasons)
+}
+
+#[test]
+fn leaf_constraints() {
+    let mut world = World::new();
+    let e = candidate(&mut world);
+    let ok = [
+        Constraint::AgeBetween(18, 65),
+        Constraint::AgeAtLeast(30),
+        Constraint::TraitAtLeast(Trait::Openness, 0.7),
+        Constraint::TraitAtMost(Trait::Neuroticism, 0.5),
+        Constraint::MinEducation(12.0),
+        Constraint::MinSkill("carpentry".into(), 0.5),
+        Constraint::Sex(Sex::Female),
+        Constraint::LivesWithin {
+            x: 0.0,
+            y: 0.0,
+            radius: 5.0,
+        },
+        Constraint::has::<Education>(),
+    ];
+    assert!(reasons(&world, e, &ok).is_empty());
+
+    let bad = [
+        Constraint::AgeLessThan(18),
+        Constraint::TraitAtLeast(Trait::Openness, 0.9),
+        Constraint::MinEducation(16.0),
+        Constraint::MinSkill("welding".into(), 0.1),
+        Constraint::Sex(Sex::Male),
+        Constraint::LivesWithin {
+            x: 0.0,
+            y: 0.0,
+            radius: 1.0,
+        },
+        Constraint::has::<Unemployed>(),
+    ];
+    let why = reasons(&world, e, &bad);
+    assert_eq!(
+        why,
+        vec![
+            "age 30 is not below 18",
+            "openness 0.80 is below 0.90",
+            "12.0 years of education, needs 16.0",
+            "welding skill 0.00, needs 0.10",
+            "sex is Female, needs Male",
+            "lives 5.0 away, limit 1.0",
+            "lacks Unemployed",
+        ]
+    );
+}
+
+#[test]
+fn combinators_and_missing_data() {
+    let mut world = World::new();
+    let e = candidate(&mut world);
+    let bare = world.spawn(Age { years: 40 }).id();
+
+    let either = Constraint::Or(vec![
+        Constraint::MinEducation(16.0),
+        Constraint::MinSkill("carpentry".into(), 0.5),
+    ]);
+    let not_minor = Constraint::Not(Box::new(Constraint::AgeLessThan(18)));
+    let both = Constraint::And(vec![either.clone(), not_minor.clone()]);
+    assert!(both.check(&world.entity(e)).is_ok());
+    assert_eq!(
+        both.to_string(),
+        "((education >= 16 years or carpentry skill >= 0.5) and not age < 18)"
+    );
+
+    // Age falls back to the hiring Age component; the rest is missing
+    assert!(not_minor.check(&world.entity(bare)).is_ok());
+    let why = reasons(
+        &world,
+        bare,
+        &[
+            either,
+            Constraint::Sex(Sex::Male),
+            Constraint::TraitAtLeast(Trait::Openness, 0.1),
+            Constraint::Not(Box::new(Constraint::AgeAtLeast(18))),
+        ],
+    );
+    assert_eq!(
+        why,
+        vec![
+            "none of: 0.0 years of education, needs 16.0 | carpentry skill 0.00, needs 0.50",
+            "sex unknown",
+            "has no personality",
+            "must not satisfy age >= 18",
+        ]
+    );
+}
+
+#[test]
+fn hiring_respects_constraints() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8));
+    let world = app.world_mut();
+    let job = world
+        .spawn(
+            Job::builder()
+                .add_role(2, 2)
+                .age_gte(18)
+                .education(12.0)
+                .build(),
+        )
+        .id();
+    let dropout = world
+        .spawn((Person { age: 30.0 }, Unemployed, Education { years: 9.0 }))
+        .id();
+    let graduate = world
+        .spawn((Person { age: 30.0 }, Unemployed, Education { years: 12.0 }))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<Job>(job).unwrap().roles[0].1,
+        vec![graduate]
+    );
+    assert!(app.world().get::<Unemployed>(dropout).is_some());
+}
@@ -0,0 +1,122 @@
+// Job referrals through the social network (what the tests mean)
+//
+// Fixture: a job with one open seat and one member, the insider. Two
+// unemployed adults apply: a stranger first, then the insider's friend.
+//
+//   (1) The friend's application is credited to the insider and, with the
+//       default bonus, beats the earlier stranger
+//   (2) The hire's Employment records who referred them; Records tracks the share
+//   (3) A zero bonus leaves first-come order in place
+//   (4) The bonus is a score boost, not a trump card: a much better fit still wins
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::RunSystemOnce;
+use bevy_time::{Real, Time};
+
+use simrs::graph::Graph;
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Employment, Job};
+use simrs::personality::{Personality, Trait};
+use simrs::records::{Records, record_referral_share};
+use simrs::social::Tie;
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+struct Fixture {
+    app: App,
+    job: Entity,
+    insider: Entity,
+    stranger: Entity,
+    friend: Entity,
+}
+
+fn fixture(hiring: HiringManagerPlugin, profile: bool) -> Fixture {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(hiring);
+
+    let world = app.world_mut();
+    let mut builder = Job::builder().add_role(2, 2).age_gte(18);
+    if profile {
+        builder = builder.prefers(Trait::Conscientiousness, 0.9, 1.0);
+    }
+    let mut job = builder.build();
+    let person = |world: &mut World, c: f64| {
+        let mut p = Personality::from_array([0.5; 6]);
+        p.conscientiousness = c;
+        world.spawn((Age { years: 30 }, Unemployed, p)).id()
+    };
+    let stranger = person(world, 0.9);
+    let friend = person(world, 0.4);
+
+    let mut contacts = Graph::<Tie>::default();
+    contacts.set(friend, Tie::friend(0.6));
+    let insider = world.spawn((Age { years: 40 }, contacts)).id();
+    job.roles[0].1.push(insider);
+    let job = world.spawn(job).id();
+    Fixture {
+        app,
+        job,
+        insider,
+        stranger,
+        friend,
+    }
+}
+
+fn members(app: &App, job: Entity) -> Vec<Entity> {
+    app.world().get::<Job>(job).unwrap().roles[0].1.clone()
+}
+
+#[test]
+fn referred_friend_is_hired_first() {
+    let Fixture {
+        mut app,
+        job,
+        insider,
+        stranger,
+        friend,
+    } = fixture(HiringManagerPlugin::new(8), false);
+    app.update();
+
+    assert_eq!(members(&app, job), vec![insider, friend]);
+    assert!(app.world().get::<Unemployed>(stranger).is_some());
+    let m = app.world().get::<Employment>(friend).unwrap();
+    assert_eq!(m.referred_by, Some(insider));
+
+    app.world_mut().insert_resource(Records::default());
+    app.world_mut()
+        .run_system_once(record_referral_share)
+        .unwrap();
+    let records = app.world().resource::<Records>();
+    assert_eq!(records.referral_share, 1.0);
+}
+
+#[test]
+fn zero_bonus_keeps_inbox_order() {
+    let Fixture {
+        mut app,
+        job,
+        insider,
+        stranger,
+        ..
+    } = fixture(HiringManagerPlugin::new(8).with_referral_bonus(0.0), false);
+    app.update();
+    assert_eq!(members(&app, job), vec![insider, stranger]);
+    let m = app.world().get::<Employment>(stranger).unwrap();
+    assert_eq!(m.referred_by, None);
+}
+
+#[test]
+fn bonus_does_not_beat_a_much_better_fit() {
+    let Fixture {
+        mut app,
+        job,
+        insider,
+        stranger,
+        ..
+    } = fixture(HiringManagerPlugin::new(8), true);
+    app.update();
+    // stranger fits 1.0; friend 0.5 + 0.25 bonus
+    assert_eq!(members(&app, job), vec![insider, stranger]);
+}
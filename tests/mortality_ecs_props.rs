@@ -41,6 +41,7 @@ fn app_with_mortality() -> App {
     app
 }
 
+#[allow(dead_code)]
 #[derive(Clone, Copy)]
 struct AgentInit {
     id: u64,
@@ -41,7 +41,7 @@ proptest! {
     #[test]
     fn hazard_bounds(a in 0u16..=130) {
         let h = hazard(a);
-        prop_assert!(0.0 <= h && h <= 1.0, "hazard out of bounds: h({a})={h}");
+        prop_assert!((0.0..=1.0).contains(&h), "hazard out of bounds: h({a})={h}");
     }
 
     // Survival decreases as you ask for “alive after more ticks”
@@ -22,6 +22,7 @@ use rand08::rngs::StdRng;
 use std::hash::{Hash, Hasher};
 use std::collections::hash_map::DefaultHasher;
 
+#[allow(dead_code)]
 // Per-agent RNG: same (seed, agent_id, tick) → same draw.
 // Prevents iteration order from deciding who dies.
 pub fn draw_u01(global_seed: u64, agent_id: u64, tick: u64) -> f32 {
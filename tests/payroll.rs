@@ -0,0 +1,112 @@
+// Wages and payroll (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated day, paydays every 30 days.
+//
+//   (1) Accounts never go negative: withdrawals stop at the balance
+//   (2) Each payday moves wage × period from the job's account to its
+//       members; unpaid roles and days between paydays pay nothing
+//   (3) A job that cannot cover its payroll pays everyone the same fraction
+//   (4) Records track total payroll and the mean/median yearly income
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::economy::{Account, PayrollConfig, PayrollPlugin};
+use simrs::jobs::Job;
+use simrs::person::{AgingConfig, Person};
+use simrs::records::{Records, record_payroll};
+
+mod clock;
+use clock::step;
+
+const PERIOD: f64 = 30.0 / 365.0;
+
+fn app(job_funds: f64) -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig {
+        years_per_sec: 1.0 / 365.0,
+    });
+    app.insert_resource(PayrollConfig {
+        period_days: 30.0,
+        initial_job_funds: job_funds,
+        initial_person_funds: 0.0,
+    });
+    app.insert_resource(Records::default());
+    app.add_plugins(PayrollPlugin)
+        .add_systems(Update, record_payroll);
+    app
+}
+
+/// A job with a 12k intern, two 36k staff and an unpaid volunteer role.
+fn staffed(app: &mut App) -> (Entity, [Entity; 4]) {
+    let world = app.world_mut();
+    let people: [Entity; 4] = std::array::from_fn(|_| world.spawn(Person::new()).id());
+    let mut job = Job::builder()
+        .add_role(0, 1)
+        .wage(12_000.0)
+        .add_role(0, 2)
+        .wage(36_000.0)
+        .add_role(0, 1)
+        .build();
+    job.roles[0].1.push(people[0]);
+    job.roles[1].1.extend([people[1], people[2]]);
+    job.roles[2].1.push(people[3]);
+    let job = world.spawn(job).id();
+    step(app, 0.0); // opens accounts
+    (job, people)
+}
+
+fn balance(app: &App, e: Entity) -> f64 {
+    app.world().get::<Account>(e).unwrap().balance
+}
+
+#[test]
+fn withdrawals_stop_at_the_balance() {
+    let mut a = Account::new(10.0);
+    assert_eq!(a.withdraw(4.0), 4.0);
+    assert_eq!(a.withdraw(100.0), 6.0);
+    assert_eq!(a.balance, 0.0);
+    a.deposit(-5.0);
+    assert_eq!(a.balance, 0.0);
+}
+
+#[test]
+fn payday_moves_wages_from_job_to_members() {
+    let mut app = app(100_000.0);
+    let (job, [intern, a, b, volunteer]) = staffed(&mut app);
+
+    step(&mut app, 29.0);
+    assert_eq!(balance(&app, a), 0.0);
+    step(&mut app, 1.0);
+
+    assert_abs_diff_eq!(balance(&app, intern), 12_000.0 * PERIOD, epsilon = 1e-6);
+    assert_abs_diff_eq!(balance(&app, a), 36_000.0 * PERIOD, epsilon = 1e-6);
+    assert_abs_diff_eq!(balance(&app, b), 36_000.0 * PERIOD, epsilon = 1e-6);
+    assert_eq!(balance(&app, volunteer), 0.0);
+    let payroll = 84_000.0 * PERIOD;
+    assert_abs_diff_eq!(balance(&app, job), 100_000.0 - payroll, epsilon = 1e-6);
+
+    step(&mut app, 0.0); // records read the payday on the next frame
+    let records = app.world().resource::<Records>();
+    assert_abs_diff_eq!(records.total_payroll, payroll, epsilon = 1e-6);
+    assert_abs_diff_eq!(records.mean_income, 28_000.0, epsilon = 1e-6);
+    assert_abs_diff_eq!(records.median_income, 36_000.0, epsilon = 1e-6);
+}
+
+#[test]
+fn short_funds_are_shared_pro_rata() {
+    let payroll = 84_000.0 * PERIOD;
+    let mut app = app(payroll / 2.0);
+    let (job, [intern, a, ..]) = staffed(&mut app);
+
+    step(&mut app, 30.0);
+    assert_eq!(balance(&app, job), 0.0);
+    assert_abs_diff_eq!(balance(&app, intern), 6_000.0 * PERIOD, epsilon = 1e-6);
+    assert_abs_diff_eq!(balance(&app, a), 18_000.0 * PERIOD, epsilon = 1e-6);
+
+    step(&mut app, 30.0);
+    assert_abs_diff_eq!(balance(&app, a), 18_000.0 * PERIOD, epsilon = 1e-6);
+}
@@ -0,0 +1,135 @@
+// Personality analytics (what the tests mean)
+//
+//   (1) Metrics: known distances, and Mahalanobis collapses to Euclidean / σ
+//       when the population covariance is σ²·I
+//   (2) invert(m) · m = I
+//   (3) Nearest neighbours are sorted, skip the target, and respect k
+//   (4) k-means recovers two well-separated archetypes
+//   (5) Recorded archetypes depend on the seed, not on when the frames fall
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::Duration;
+
+use simrs::personality::analytics::{covariance, invert, Matrix};
+use simrs::personality::{kmeans, nearest_neighbours, ArchetypeConfig, Metric, Personality};
+use simrs::records::{Records, record_archetypes};
+
+fn p(a: [f64; 6]) -> Personality {
+    Personality::from_array(a)
+}
+
+#[test]
+fn euclidean_and_cosine_known_values() {
+    let a = p([0.0, 0.0, 0.0, 0.0, 3.0, 4.0]);
+    let b = p([0.0; 6]);
+    assert_abs_diff_eq!(Metric::Euclidean.distance(&a, &b), 5.0);
+    assert_abs_diff_eq!(Metric::Cosine.distance(&a, &a), 0.0, epsilon = 1e-12);
+    let c = p([1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    let d = p([0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_abs_diff_eq!(Metric::Cosine.distance(&c, &d), 1.0);
+}
+
+#[test]
+fn mahalanobis_with_isotropic_covariance_scales_euclidean() {
+    let sigma2 = 4.0;
+    let cov: Matrix = std::array::from_fn(|i| std::array::from_fn(|j| if i == j { sigma2 } else { 0.0 }));
+    let metric = Metric::Mahalanobis(Box::new(invert(&cov).unwrap()));
+    let a = p([1.0, 2.0, 3.0, 0.0, 0.0, 0.0]);
+    let b = p([0.0; 6]);
+    let expected = Metric::Euclidean.distance(&a, &b) / sigma2.sqrt();
+    assert_abs_diff_eq!(metric.distance(&a, &b), expected, epsilon = 1e-12);
+}
+
+#[test]
+fn inverse_times_matrix_is_identity() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let cfg = simrs::personality::PersonalityConfig::default();
+    let l = cfg.covariance_factor().unwrap();
+    let pts: Vec<_> = (0..500)
+        .map(|_| simrs::personality::sample_personality(&cfg, &l, &mut rng).as_array())
+        .collect();
+    let cov = covariance(&pts);
+    let inv = invert(&cov).unwrap();
+    for (i, row) in inv.iter().enumerate() {
+        for j in 0..6 {
+            let v: f64 = row.iter().zip(&cov).map(|(a, c)| a * c[j]).sum();
+            assert_abs_diff_eq!(v, if i == j { 1.0 } else { 0.0 }, epsilon = 1e-9);
+        }
+    }
+    assert!(invert(&[[0.0; 6]; 6]).is_none());
+}
+
+#[test]
+fn nearest_neighbours_are_sorted_and_exclude_target() {
+    let mut world = World::new();
+    let ids: Vec<Entity> = (0..4).map(|_| world.spawn_empty().id()).collect();
+    let pool: Vec<_> = ids
+        .iter()
+        .zip([0.0, 0.3, 0.1, 0.9])
+        .map(|(&e, x)| (e, p([x; 6])))
+        .collect();
+
+    let nn = nearest_neighbours((ids[0], &pool[0].1), &pool, 2, &Metric::Euclidean);
+    assert_eq!(nn.iter().map(|(e, _)| *e).collect::<Vec<_>>(), vec![ids[2], ids[1]]);
+    assert!(nn[0].1 <= nn[1].1);
+}
+
+#[test]
+fn kmeans_finds_two_blobs() {
+    let mut pts = Vec::new();
+    for i in 0..50 {
+        let jitter = i as f64 * 1e-3;
+        pts.push([0.1 + jitter; 6]);
+        pts.push([0.9 - jitter; 6]);
+    }
+    let clusters = kmeans(&pts, 2, 50, &mut StdRng::seed_from_u64(1));
+    assert_eq!(clusters.sizes(), vec![50, 50]);
+    // members of each blob share a label
+    assert!(clusters.assignments.chunks(2).all(|pair| pair[0] != pair[1]));
+    let first = clusters.assignments[0];
+    assert!(clusters.assignments.iter().step_by(2).all(|&a| a == first));
+
+    let archetypes = clusters.archetypes();
+    assert_eq!(archetypes.len(), 2);
+    assert!(archetypes.iter().any(|a| a.centroid.openness < 0.2));
+    assert!(archetypes.iter().any(|a| a.centroid.openness > 0.8));
+}
+
+#[test]
+fn recorded_archetypes_ignore_frame_timing() {
+    let run = |frame_secs: f64| {
+        let mut app = App::new();
+        app.insert_resource(Time::<Real>::default())
+            .insert_resource(ArchetypeConfig {
+                k: 3,
+                iterations: 1,
+                interval_secs: 1.0,
+                seed: 7,
+            })
+            .init_resource::<Records>()
+            .add_systems(Update, record_archetypes);
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..60 {
+            let traits: [f64; 6] = std::array::from_fn(|_| rng.random());
+            app.world_mut().spawn(p(traits));
+        }
+        for secs in [0.0, frame_secs] {
+            app.world_mut()
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(Duration::from_secs_f64(secs));
+            app.update();
+        }
+        app.world()
+            .resource::<Records>()
+            .archetypes
+            .iter()
+            .map(|a| (a.centroid.as_array(), a.size))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(run(1.25), run(1.75));
+}
@@ -0,0 +1,128 @@
+// Lifetime personality development (what the tests mean)
+//
+//   (1) Age curves interpolate linearly and hold at their ends
+//   (2) Without noise, adults drift the way the curves say (C up, N down)
+//   (3) Same seed => same trajectory, noise included
+//   (4) A parent's death nudges the child's traits by the bereavement vector
+//   (5) A marriage nudges traits by the marriage vector
+
+use bevy_app::prelude::*;
+use bevy_time::{Real, Time};
+use approx::assert_abs_diff_eq;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Duration;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::mortality::Death;
+use simrs::person::{AgingConfig, Parents, Person};
+use simrs::personality::{
+    AgeCurve, DevelopmentConfig, LifeEvent, LifeEventKind, Personality, PersonalityPlugin,
+};
+
+fn app_with_development(seed: u64, dev: DevelopmentConfig) -> App {
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(seed)));
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(dev);
+    app.add_plugins(PersonalityPlugin);
+    app
+}
+
+/// One frame that lasts `years` simulated years.
+fn step(app: &mut App, years: f64) {
+    app.world_mut()
+        .resource_mut::<Time<Real>>()
+        .update_with_duration(Duration::from_secs_f64(years));
+    app.update();
+}
+
+fn mid() -> Personality {
+    Personality::from_array([0.5; 6])
+}
+
+#[test]
+fn age_curve_interpolates() {
+    let c = AgeCurve(vec![(20.0, 0.0), (40.0, 1.0)]);
+    assert_abs_diff_eq!(c.rate_at(10.0), 0.0);
+    assert_abs_diff_eq!(c.rate_at(30.0), 0.5);
+    assert_abs_diff_eq!(c.rate_at(90.0), 1.0);
+    assert_abs_diff_eq!(AgeCurve::default().rate_at(30.0), 0.0);
+}
+
+#[test]
+fn adults_mature_along_age_curves() {
+    let dev = DevelopmentConfig {
+        volatility: 0.0,
+        ..Default::default()
+    };
+    let mut app = app_with_development(1, dev);
+    let e = app.world_mut().spawn((Person { age: 30.0 }, mid())).id();
+    for _ in 0..20 {
+        step(&mut app, 1.0);
+    }
+    let p = app.world().get::<Personality>(e).unwrap();
+    assert!(p.conscientiousness > 0.5, "C should rise: {}", p.conscientiousness);
+    assert!(p.neuroticism < 0.5, "N should fall: {}", p.neuroticism);
+}
+
+#[test]
+fn seeded_trajectories_repeat() {
+    let run = |seed| {
+        let mut app = app_with_development(seed, DevelopmentConfig::default());
+        let e = app.world_mut().spawn((Person { age: 25.0 }, mid())).id();
+        for _ in 0..10 {
+            step(&mut app, 0.5);
+        }
+        app.world().get::<Personality>(e).unwrap().as_array()
+    };
+    assert_eq!(run(9), run(9));
+    assert_ne!(run(9), run(10));
+}
+
+#[test]
+fn parent_death_bereaves_child() {
+    let dev = DevelopmentConfig {
+        volatility: 0.0,
+        curves: Default::default(),
+        ..Default::default()
+    };
+    let nudge = dev.bereavement;
+    let mut app = app_with_development(1, dev);
+    let world = app.world_mut();
+    let a = world.spawn((Person { age: 40.0 }, mid())).id();
+    let b = world.spawn((Person { age: 40.0 }, mid())).id();
+    let child = world.spawn((Person { age: 10.0 }, mid(), Parents(a, b))).id();
+
+    app.world_mut().send_event(Death { entity: a });
+    step(&mut app, 0.0);
+
+    let got = app.world().get::<Personality>(child).unwrap().as_array();
+    for (g, n) in got.iter().zip(nudge) {
+        assert_abs_diff_eq!(*g, 0.5 + n, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn marriage_nudges_traits() {
+    let dev = DevelopmentConfig {
+        volatility: 0.0,
+        curves: Default::default(),
+        ..Default::default()
+    };
+    let nudge = dev.marriage;
+    let mut app = app_with_development(1, dev);
+    let e = app.world_mut().spawn((Person { age: 30.0 }, mid())).id();
+
+    app.world_mut().send_event(LifeEvent {
+        entity: e,
+        kind: LifeEventKind::Marriage,
+    });
+    step(&mut app, 0.0);
+
+    let got = app.world().get::<Personality>(e).unwrap().as_array();
+    for (g, n) in got.iter().zip(nudge) {
+        assert_abs_diff_eq!(*g, 0.5 + n, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,134 @@
+// Personality generation (what the tests mean)
+//
+//   (1) Bounds: every sampled trait lies in [cfg.min, cfg.max]
+//   (2) Correlation: a large sample reproduces the configured trait correlation
+//   (3) ECS: every Person without a Personality receives one
+//   (4) Same seed => same personalities
+//   (5) A correlation matrix that is not positive-definite is reported and
+//       traits are drawn independently instead
+
+use bevy_app::prelude::*;
+use bevy_time::{Real, Time};
+use bevy_ecs::prelude::*;
+use proptest::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::person::Person;
+use simrs::personality::{
+    CovarianceFactor, Personality, PersonalityConfig, PersonalityPlugin, sample_personality,
+};
+
+fn pearson(xs: &[f64], ys: &[f64]) -> f64 {
+    let n = xs.len() as f64;
+    let mx = xs.iter().sum::<f64>() / n;
+    let my = ys.iter().sum::<f64>() / n;
+    let cov: f64 = xs.iter().zip(ys).map(|(x, y)| (x - mx) * (y - my)).sum();
+    let vx: f64 = xs.iter().map(|x| (x - mx).powi(2)).sum();
+    let vy: f64 = ys.iter().map(|y| (y - my).powi(2)).sum();
+    cov / (vx.sqrt() * vy.sqrt())
+}
+
+fn app_with_personality(seed: u64) -> App {
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(seed)));
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(PersonalityPlugin);
+    app
+}
+
+fn spawned_personalities(app: &mut App) -> Vec<[f64; 6]> {
+    let world = app.world_mut();
+    let mut q = world.query::<(Entity, &Personality)>();
+    let mut out: Vec<_> = q.iter(world).map(|(e, p)| (e, p.as_array())).collect();
+    out.sort_by_key(|(e, _)| *e);
+    out.into_iter().map(|(_, p)| p).collect()
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn traits_are_clamped(seed in any::<u64>(), sd in 0.01f64..2.0) {
+        let cfg = PersonalityConfig { std_devs: [sd; 6], ..Default::default() };
+        let l = cfg.covariance_factor().unwrap();
+        let mut rng = StdRng::seed_from_u64(seed);
+        for _ in 0..200 {
+            let p = sample_personality(&cfg, &l, &mut rng);
+            for t in p.as_array() {
+                prop_assert!(t >= cfg.min && t <= cfg.max, "trait out of bounds: {t}");
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_reproducibility(seed in any::<u64>(), n in 1usize..50) {
+        let mut a = app_with_personality(seed);
+        let mut b = app_with_personality(seed);
+        for app in [&mut a, &mut b] {
+            for _ in 0..n {
+                app.world_mut().spawn(Person::new());
+            }
+            app.update();
+        }
+        prop_assert_eq!(spawned_personalities(&mut a), spawned_personalities(&mut b));
+    }
+}
+
+#[test]
+fn sample_matches_configured_correlation() {
+    // wide bounds so clamping does not bias the estimate
+    let mut cfg = PersonalityConfig {
+        min: f64::NEG_INFINITY,
+        max: f64::INFINITY,
+        ..Default::default()
+    };
+    cfg.correlation[1][4] = -0.6;
+    cfg.correlation[4][1] = -0.6;
+    let l = cfg.covariance_factor().unwrap();
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let samples: Vec<_> = (0..20_000).map(|_| sample_personality(&cfg, &l, &mut rng)).collect();
+    let c: Vec<f64> = samples.iter().map(|p| p.conscientiousness).collect();
+    let n: Vec<f64> = samples.iter().map(|p| p.neuroticism).collect();
+
+    approx::assert_abs_diff_eq!(pearson(&c, &n), -0.6, epsilon = 0.03);
+}
+
+#[test]
+fn every_person_gets_a_personality() {
+    let mut app = app_with_personality(1);
+    for _ in 0..25 {
+        app.world_mut().spawn(Person::new());
+    }
+    app.update();
+
+    let world = app.world_mut();
+    let missing = world
+        .query_filtered::<Entity, (With<Person>, Without<Personality>)>()
+        .iter(world)
+        .count();
+    assert_eq!(missing, 0);
+}
+
+#[test]
+fn invalid_correlation_falls_back_to_independent_traits() {
+    let mut cfg = PersonalityConfig::default();
+    cfg.correlation[0][1] = 2.0;
+    cfg.correlation[1][0] = 2.0;
+    assert!(cfg.covariance_factor().is_none());
+
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(1)))
+        .insert_resource(Time::<Real>::default())
+        .insert_resource(cfg.clone())
+        .add_plugins(PersonalityPlugin);
+    assert_eq!(
+        *app.world().resource::<CovarianceFactor>(),
+        CovarianceFactor::uncorrelated(&cfg.std_devs)
+    );
+    app.world_mut().spawn(Person::new());
+    app.update();
+    assert_eq!(spawned_personalities(&mut app).len(), 1);
+}
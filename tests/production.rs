@@ -0,0 +1,255 @@
+// Production into inventories (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated year.
+//
+//   (1) Productivity is 1 for an average member; conscientiousness and, in
+//       roles that train skills, skill raise it
+//   (2) Recipes make rate x summed productivity units per year as Item
+//       entities in the job's Inventory; absent members make nothing
+//   (3) Inputs are taken from the Inventory and despawned; without them
+//       production stalls with at most one unit banked
+//   (4) Job files define recipes, and bad ones are rejected; the builder
+//       ignores zero-count inputs
+//   (5) A full inventory pauses production until items leave it
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::inventory::component::{Inventory, Item};
+use simrs::jobs::{Absent, CatalogError, Job, JobCatalog, Recipe};
+use simrs::person::{AgingConfig, Skills};
+use simrs::personality::Personality;
+use simrs::production::{Produced, ProductionConfig, ProductionPlugin, productivity};
+
+mod clock;
+use clock::step;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.add_plugins(ProductionPlugin);
+    step(&mut app, 0.0);
+    app
+}
+
+/// A job whose one role holds `n` average members.
+fn workshop(app: &mut App, job: Job, n: usize) -> (Entity, Vec<Entity>) {
+    let world = app.world_mut();
+    let members: Vec<Entity> = (0..n)
+        .map(|_| world.spawn(Personality::from_array([0.5; 6])).id())
+        .collect();
+    let mut job = job;
+    job.roles[0].1 = members.clone();
+    (world.spawn(job).id(), members)
+}
+
+fn stock(app: &App, job: Entity, kind: &str) -> usize {
+    let items = app.world().get::<Inventory>(job).unwrap().items.clone();
+    items
+        .iter()
+        .filter(|&&i| app.world().get::<Item>(i).is_some_and(|it| it.kind == kind))
+        .count()
+}
+
+fn item(app: &mut App, kind: &str) -> Entity {
+    app.world_mut()
+        .spawn(Item {
+            kind: kind.to_string(),
+        })
+        .id()
+}
+
+#[test]
+fn productivity_follows_personality_and_skill() {
+    let cfg = ProductionConfig::default();
+    let plain = Job::builder().add_role(0, 1).build().roles.remove(0).0;
+    let skilled = Job::builder()
+        .add_role(0, 1)
+        .trains("baking")
+        .build()
+        .roles
+        .remove(0)
+        .0;
+    let average = Personality::from_array([0.5; 6]);
+    let mut diligent = average;
+    diligent.conscientiousness = 0.9;
+    let mut expert = Skills::default();
+    expert.set("baking", 1.0);
+
+    assert_abs_diff_eq!(productivity(&cfg, &plain, Some(&average), None), 1.0);
+    assert_abs_diff_eq!(productivity(&cfg, &plain, None, Some(&expert)), 1.0);
+    assert_abs_diff_eq!(productivity(&cfg, &plain, Some(&diligent), None), 1.4);
+    assert_abs_diff_eq!(productivity(&cfg, &skilled, Some(&average), None), 0.5);
+    assert_abs_diff_eq!(
+        productivity(&cfg, &skilled, Some(&average), Some(&expert)),
+        1.5
+    );
+    let harsh = ProductionConfig {
+        conscientiousness_gain: 10.0,
+        ..cfg
+    };
+    let mut idle = average;
+    idle.conscientiousness = 0.0;
+    assert_eq!(productivity(&harsh, &plain, Some(&idle), None), 0.0);
+}
+
+#[test]
+fn members_fill_the_inventory() {
+    let mut app = app();
+    let (farm, members) = workshop(
+        &mut app,
+        Job::builder().add_role(0, 5).produces("grain", 3.0).build(),
+        2,
+    );
+    step(&mut app, 0.5);
+    assert_eq!(stock(&app, farm, "grain"), 3);
+    let events: Vec<Produced> = app
+        .world()
+        .resource::<Events<Produced>>()
+        .iter_current_update_events()
+        .cloned()
+        .collect();
+    assert_eq!(
+        events,
+        vec![Produced {
+            job: farm,
+            role_index: 0,
+            kind: "grain".into(),
+            count: 3
+        }]
+    );
+
+    app.world_mut().entity_mut(members[0]).insert(Absent);
+    step(&mut app, 0.5);
+    assert_eq!(stock(&app, farm, "grain"), 4);
+    step(&mut app, 0.5);
+    assert_eq!(stock(&app, farm, "grain"), 6);
+}
+
+#[test]
+fn inputs_are_used_up() {
+    let mut app = app();
+    let (bakery, _) = workshop(
+        &mut app,
+        Job::builder()
+            .add_role(0, 1)
+            .produces("bread", 5.0)
+            .consumes("flour", 2)
+            .build(),
+        1,
+    );
+    let flour: Vec<Entity> = (0..3).map(|_| item(&mut app, "flour")).collect();
+    let salt = item(&mut app, "salt");
+    let mut held = flour.clone();
+    held.push(salt);
+    app.world_mut()
+        .entity_mut(bakery)
+        .insert(Inventory { items: held });
+
+    step(&mut app, 1.0);
+    assert_eq!(stock(&app, bakery, "bread"), 1);
+    assert_eq!(stock(&app, bakery, "flour"), 1);
+    assert_eq!(stock(&app, bakery, "salt"), 1);
+    let gone = flour
+        .iter()
+        .filter(|&&f| app.world().get_entity(f).is_err())
+        .count();
+    assert_eq!(gone, 2);
+
+    // a long stall banks only one unit
+    step(&mut app, 10.0);
+    let more: Vec<Entity> = (0..9).map(|_| item(&mut app, "flour")).collect();
+    app.world_mut()
+        .get_mut::<Inventory>(bakery)
+        .unwrap()
+        .items
+        .extend(more);
+    step(&mut app, 0.1);
+    assert_eq!(stock(&app, bakery, "bread"), 2);
+    assert_eq!(stock(&app, bakery, "flour"), 8);
+}
+
+#[test]
+fn job_files_define_recipes() {
+    let mut catalog = JobCatalog::default();
+    catalog
+        .load_str(
+            "a.ron",
+            r#"[(name: "mill", roles: [(min: 1, max: 2, produces: [
+                (output: "flour", rate: 100.0, inputs: [("grain", 3)]),
+            ])])]"#,
+        )
+        .unwrap();
+    let mill = catalog.instantiate("mill").unwrap();
+    assert_eq!(
+        mill.roles[0].0.produces,
+        vec![Recipe {
+            output: "flour".into(),
+            rate: 100.0,
+            inputs: vec![("grain".into(), 3)],
+        }]
+    );
+
+    let free = Job::builder()
+        .add_role(0, 1)
+        .produces("water", 1.0)
+        .consumes("bucket", 0)
+        .build();
+    assert!(free.roles[0].0.produces[0].inputs.is_empty());
+
+    let err = catalog
+        .load_str(
+            "b.ron",
+            r#"[(name: "forge", roles: [(min: 1, max: 2, produces: [
+                (output: "nails", rate: -1.0, inputs: [("iron", 0)]),
+            ])])]"#,
+        )
+        .unwrap_err();
+    let CatalogError::Invalid { problems, .. } = err else {
+        panic!("expected invalid, got {err}");
+    };
+    assert_eq!(
+        problems,
+        vec![
+            "forge/role 0: nails rate -1 must be zero or more",
+            "forge/role 0: nails input \"iron\" x0 is empty",
+        ]
+    );
+}
+
+#[test]
+fn full_inventories_pause_production() {
+    let mut app = app();
+    app.world_mut()
+        .resource_mut::<ProductionConfig>()
+        .inventory_capacity = 4;
+    let (farm, _) = workshop(
+        &mut app,
+        Job::builder()
+            .add_role(0, 1)
+            .produces("grain", 10.0)
+            .build(),
+        1,
+    );
+    step(&mut app, 1.0);
+    assert_eq!(stock(&app, farm, "grain"), 4);
+    let items = app.world_mut().query::<&Item>().iter(app.world()).count();
+    assert_eq!(items, 4);
+
+    // selling two makes room for two more
+    let sold: Vec<Entity> = app
+        .world_mut()
+        .get_mut::<Inventory>(farm)
+        .unwrap()
+        .items
+        .drain(..2)
+        .collect();
+    for item in sold {
+        app.world_mut().despawn(item);
+    }
+    step(&mut app, 1.0);
+    assert_eq!(stock(&app, farm, "grain"), 4);
+}
@@ -0,0 +1,149 @@
+// Role hierarchies and internal promotions (what the tests mean)
+//
+// Fixture: a three-level job, director <- manager <- clerk, each role
+// reporting to and promoting into the one above.
+//
+//   (1) Roles know their supervisors and the roles that feed into them
+//   (2) A senior vacancy is filled from the feeder role by its best
+//       qualified member, whose Employment follows the move
+//   (3) Vacancies cascade down within one frame; only the bottom seat is
+//       advertised and hired from outside
+//   (4) Catalog files refer to roles by name and reject broken paths
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{CatalogError, Employment, Job, JobCatalog, Promoted};
+use simrs::personality::{Personality, Trait};
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8));
+    app
+}
+
+fn firm() -> Job {
+    Job::builder()
+        .add_role(1, 1) // 0: director
+        .age_gte(40)
+        .add_role(1, 1) // 1: manager
+        .reports_to(0)
+        .promotes_to(0)
+        .prefers(Trait::Conscientiousness, 1.0, 1.0)
+        .add_role(2, 2) // 2: clerk
+        .reports_to(1)
+        .promotes_to(1)
+        .build()
+}
+
+fn staff(world: &mut World, job: Entity, role_index: usize, age: u8, c: f64) -> Entity {
+    let mut p = Personality::from_array([0.5; 6]);
+    p.conscientiousness = c;
+    let e = world
+        .spawn((Age { years: age }, p, Employment::new(job, role_index)))
+        .id();
+    world.get_mut::<Job>(job).unwrap().roles[role_index]
+        .1
+        .push(e);
+    e
+}
+
+fn roster(app: &App, job: Entity) -> Vec<Vec<Entity>> {
+    let job = app.world().get::<Job>(job).unwrap();
+    job.roles.iter().map(|(_, m)| m.clone()).collect()
+}
+
+#[test]
+fn roles_know_their_hierarchy() {
+    let mut world = World::new();
+    let job = world.spawn(firm()).id();
+    let boss = staff(&mut world, job, 0, 50, 0.5);
+    let job = world.get::<Job>(job).unwrap();
+    assert_eq!(job.supervisors(1), &[boss]);
+    assert!(job.supervisors(0).is_empty());
+    assert_eq!(job.feeders(1).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(job.feeders(2).count(), 0);
+}
+
+#[test]
+fn vacancies_cascade_down_the_hierarchy() {
+    let mut app = app();
+    let world = app.world_mut();
+    let job = world.spawn(firm()).id();
+    let manager = staff(world, job, 1, 45, 0.5);
+    let diligent = staff(world, job, 2, 30, 0.9);
+    let careless = staff(world, job, 2, 30, 0.2);
+    let outsider = world.spawn((Age { years: 25 }, Unemployed)).id();
+
+    app.update();
+
+    // manager -> director; the diligent clerk -> manager; outsider hired as clerk
+    assert_eq!(
+        roster(&app, job),
+        vec![vec![manager], vec![diligent], vec![careless, outsider]]
+    );
+    let world = app.world();
+    let m = world.get::<Employment>(diligent).unwrap();
+    assert_eq!(m.role_index, 1);
+    assert!((m.fit.unwrap() - 0.9).abs() < 1e-9);
+    assert_eq!(world.get::<Employment>(manager).unwrap().role_index, 0);
+
+    let events = world.resource::<Events<Promoted>>();
+    let moves: Vec<_> = events
+        .iter_current_update_events()
+        .map(|p| (p.person, p.from, p.to))
+        .collect();
+    assert_eq!(moves, vec![(manager, 1, 0), (diligent, 2, 1)]);
+}
+
+#[test]
+fn unqualified_members_are_passed_over() {
+    let mut app = app();
+    let world = app.world_mut();
+    let job = world.spawn(firm()).id();
+    let young = staff(world, job, 1, 35, 0.9);
+    let boss = staff(world, job, 0, 60, 0.5);
+    world.get_mut::<Job>(job).unwrap().roles[0].1.clear();
+    world.despawn(boss);
+
+    app.update();
+
+    // the director role needs 40+, so it stays open for outside applicants
+    assert_eq!(roster(&app, job)[0], Vec::<Entity>::new());
+    assert_eq!(roster(&app, job)[1], vec![young]);
+}
+
+#[test]
+fn catalog_resolves_role_names() {
+    let mut catalog = JobCatalog::default();
+    let ok = r#"[(name: "firm", roles: [
+        (name: "boss", min: 1, max: 1),
+        (name: "staff", min: 1, max: 5, reports_to: "boss", promotes_to: "boss"),
+    ])]"#;
+    catalog.load_str("firm.ron", ok).unwrap();
+    let staff = &catalog.get("firm").unwrap().roles[1];
+    assert_eq!((staff.reports_to, staff.promotes_to), (Some(0), Some(0)));
+
+    let bad = r#"[(name: "loop", roles: [
+        (name: "a", min: 1, max: 1, promotes_to: "b"),
+        (name: "b", min: 1, max: 1, promotes_to: "a", reports_to: "ceo"),
+        (name: "c", min: 1, max: 1, promotes_to: "c"),
+    ])]"#;
+    let err = catalog.load_str("loop.ron", bad).unwrap_err();
+    let CatalogError::Invalid { problems, .. } = &err else {
+        panic!("expected validation errors, got {err}");
+    };
+    assert_eq!(
+        problems,
+        &vec![
+            "loop/b: reports_to names unknown role \"ceo\"".to_string(),
+            "loop/c: promotes_to names the role itself".to_string(),
+            "loop: promotion path loops".to_string(),
+        ]
+    );
+}
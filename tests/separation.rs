@@ -0,0 +1,240 @@
+// Quits, dismissals and layoffs (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated year.
+//
+//   (1) Quit hazard is the base rate for an average, averagely fitting
+//       member; neuroticism and poor fit raise it, conscientiousness lowers it
+//   (2) Dismissal hazard is zero above the performance threshold
+//   (3) Shrinking a role lays off its most recent hires
+//   (4) Quitters and dismissed members leave their role, become Unemployed,
+//       lose their Employment and are reported with the reason; only
+//       involuntary separations count as a job loss
+//   (5) Members of compulsory roles neither quit nor are dismissed
+//   (6) Checking for excess members leaves jobs within capacity untouched,
+//       so change detection on `Job` stays meaningful
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::gregslist::VacancyDirty;
+use simrs::hiring_manager::Unemployed;
+use simrs::jobs::{Employment, Job};
+use simrs::person::AgingConfig;
+use simrs::personality::{LifeEvent, LifeEventKind, Personality};
+use simrs::separation::system::lay_off_excess;
+use simrs::separation::{
+    Separated, SeparationConfig, SeparationPlugin, SeparationReason, dismissal_hazard, performance,
+    quit_hazard,
+};
+
+mod clock;
+use clock::step;
+
+fn app(cfg: SeparationConfig) -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(3)));
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(cfg);
+    app.add_plugins(SeparationPlugin);
+    app
+}
+
+fn with(conscientiousness: f64, neuroticism: f64) -> Personality {
+    let mut p = Personality::from_array([0.5; 6]);
+    p.conscientiousness = conscientiousness;
+    p.neuroticism = neuroticism;
+    p
+}
+
+fn matched(job: Entity, fit: Option<f64>) -> Employment {
+    Employment {
+        fit,
+        ..Employment::new(job, 0)
+    }
+}
+
+/// A one-role job holding one member per personality, hired in order.
+fn staffed(app: &mut App, people: &[Personality]) -> (Entity, Vec<Entity>) {
+    let world = app.world_mut();
+    let job = world
+        .spawn(Job::builder().add_role(0, people.len() as u32).build())
+        .id();
+    let members: Vec<Entity> = people
+        .iter()
+        .map(|&p| world.spawn((p, matched(job, None))).id())
+        .collect();
+    world.get_mut::<Job>(job).unwrap().roles[0].1 = members.clone();
+    (job, members)
+}
+
+fn separations(app: &App) -> Vec<(Entity, SeparationReason)> {
+    let events = app.world().resource::<Events<Separated>>();
+    events
+        .iter_current_update_events()
+        .map(|s| (s.person, s.reason))
+        .collect()
+}
+
+fn job_losses(app: &App) -> usize {
+    let events = app.world().resource::<Events<LifeEvent>>();
+    events
+        .iter_current_update_events()
+        .filter(|e| e.kind == LifeEventKind::JobLoss)
+        .count()
+}
+
+fn left(app: &App, job: Entity, person: Entity) -> bool {
+    let world = app.world();
+    !world.get::<Job>(job).unwrap().roles[0].1.contains(&person)
+        && world.get::<Unemployed>(person).is_some()
+        && world.get::<Employment>(person).is_none()
+}
+
+#[test]
+fn hazards_follow_personality_and_fit() {
+    let cfg = SeparationConfig::default();
+    let job = Entity::from_raw(0);
+    let avg = with(0.5, 0.5);
+    let base = quit_hazard(&cfg, Some(&avg), None);
+    assert!((base - cfg.quit_rate_per_year).abs() < 1e-12);
+    assert_eq!(quit_hazard(&cfg, None, None), base);
+    assert!(quit_hazard(&cfg, Some(&with(0.5, 0.9)), None) > base);
+    assert!(quit_hazard(&cfg, Some(&with(0.9, 0.5)), None) < base);
+    assert!(quit_hazard(&cfg, Some(&avg), Some(&matched(job, Some(0.1)))) > base);
+    assert!(quit_hazard(&cfg, Some(&avg), Some(&matched(job, Some(0.9)))) < base);
+
+    assert_eq!(dismissal_hazard(&cfg, Some(&avg), None), 0.0);
+    let poor = matched(job, Some(0.0));
+    assert_eq!(performance(Some(&with(0.0, 0.5)), Some(&poor)), 0.0);
+    assert_eq!(
+        dismissal_hazard(&cfg, Some(&with(0.0, 0.5)), Some(&poor)),
+        cfg.dismissal_rate_per_year
+    );
+}
+
+#[test]
+fn shrinking_a_role_lays_off_recent_hires() {
+    let mut app = app(SeparationConfig {
+        quit_rate_per_year: 0.0,
+        dismissal_rate_per_year: 0.0,
+        ..Default::default()
+    });
+    let (job, members) = staffed(&mut app, &[with(0.5, 0.5); 3]);
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[0].0.max = 1;
+    step(&mut app, 0.0);
+
+    assert_eq!(
+        app.world().get::<Job>(job).unwrap().roles[0].1,
+        vec![members[0]]
+    );
+    assert!(left(&app, job, members[1]) && left(&app, job, members[2]));
+    let mut laid_off = separations(&app);
+    laid_off.sort_by_key(|&(e, _)| e);
+    assert_eq!(
+        laid_off,
+        vec![
+            (members[1], SeparationReason::LaidOff),
+            (members[2], SeparationReason::LaidOff)
+        ]
+    );
+    assert_eq!(job_losses(&app), 2);
+    let dirty = app.world().resource::<Events<VacancyDirty>>();
+    assert_eq!(dirty.iter_current_update_events().count(), 1);
+}
+
+#[test]
+fn quitting_is_voluntary() {
+    let mut app = app(SeparationConfig {
+        quit_rate_per_year: 1e9,
+        dismissal_rate_per_year: 0.0,
+        ..Default::default()
+    });
+    let (job, members) = staffed(&mut app, &[with(0.5, 0.5); 2]);
+    step(&mut app, 0.0);
+    step(&mut app, 1.0);
+
+    assert!(members.iter().all(|&m| left(&app, job, m)));
+    assert!(
+        separations(&app)
+            .iter()
+            .all(|&(_, r)| r == SeparationReason::Quit)
+    );
+    assert_eq!(separations(&app).len(), 2);
+    assert_eq!(job_losses(&app), 0);
+}
+
+#[test]
+fn poor_performers_are_dismissed() {
+    let mut app = app(SeparationConfig {
+        quit_rate_per_year: 0.0,
+        dismissal_rate_per_year: 1e9,
+        ..Default::default()
+    });
+    let (job, members) = staffed(&mut app, &[with(0.9, 0.5), with(0.05, 0.5)]);
+    step(&mut app, 0.0);
+    step(&mut app, 1.0);
+
+    assert!(!left(&app, job, members[0]));
+    assert!(left(&app, job, members[1]));
+    assert_eq!(
+        separations(&app),
+        vec![(members[1], SeparationReason::Dismissed)]
+    );
+    assert_eq!(job_losses(&app), 1);
+}
+
+#[test]
+fn compulsory_roles_are_exempt() {
+    let mut app = app(SeparationConfig {
+        quit_rate_per_year: 1e9,
+        dismissal_rate_per_year: 1e9,
+        ..Default::default()
+    });
+    let (job, members) = staffed(&mut app, &[with(0.05, 0.9)]);
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[0]
+        .0
+        .compulsory = true;
+    step(&mut app, 0.0);
+    step(&mut app, 1.0);
+
+    assert!(!left(&app, job, members[0]));
+    assert!(separations(&app).is_empty());
+}
+
+#[derive(Resource, Default)]
+struct Touched(Vec<Entity>);
+
+#[test]
+fn jobs_within_capacity_are_not_touched() {
+    let mut app = App::new();
+    app.init_resource::<Touched>()
+        .add_event::<VacancyDirty>()
+        .add_event::<Separated>()
+        .add_event::<LifeEvent>()
+        .add_systems(
+            Update,
+            (
+                lay_off_excess,
+                |q: Query<Entity, Changed<Job>>, mut t: ResMut<Touched>| {
+                    t.0 = q.iter().collect();
+                },
+            )
+                .chain(),
+        );
+    staffed(&mut app, &[with(0.5, 0.5); 2]);
+    let (over, members) = staffed(&mut app, &[with(0.5, 0.5); 2]);
+    app.world_mut().get_mut::<Job>(over).unwrap().roles[0].0.max = 1;
+    app.update();
+    assert_eq!(
+        app.world().get::<Job>(over).unwrap().roles[0].1,
+        [members[0]]
+    );
+
+    app.update();
+    assert!(app.world().resource::<Touched>().0.is_empty());
+}
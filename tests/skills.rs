@@ -0,0 +1,132 @@
+// Skills and experience at work (what the tests mean)
+//
+// Fixture: 1 real second = 1 simulated year; learning 0.5/yr, decay 0.1/yr.
+//
+//   (1) Attending members approach 1 in the skills their role trains,
+//       gaining experience year for year; absence pauses learning
+//   (2) Skills outlive the job and decay slowly once unused
+//   (3) Hiring ranks applicants by skill in what the role trains
+
+use approx::assert_abs_diff_eq;
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use std::collections::HashMap;
+
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{Absent, Employment, Job};
+use simrs::person::{AgingConfig, Experience, Skills};
+use simrs::skills::{SkillConfig, SkillsPlugin};
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+mod clock;
+use clock::step;
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(SkillConfig {
+        learning_rate_per_year: 0.5,
+        intelligence_gain: 0.0,
+        decay_per_year: 0.1,
+    });
+    app.add_plugins(SkillsPlugin);
+    step(&mut app, 0.0);
+    app
+}
+
+fn welder(app: &mut App) -> (Entity, Entity) {
+    let world = app.world_mut();
+    let job = world
+        .spawn(Job::builder().add_role(0, 1).trains("welding").build())
+        .id();
+    let person = world
+        .spawn((
+            Employment::new(job, 0),
+            Skills(HashMap::from([("cooking".to_string(), 0.8)])),
+        ))
+        .id();
+    world.get_mut::<Job>(job).unwrap().roles[0].1.push(person);
+    (job, person)
+}
+
+fn level(app: &App, e: Entity, skill: &str) -> f32 {
+    app.world().get::<Skills>(e).unwrap().level(skill)
+}
+
+#[test]
+fn members_learn_what_their_role_trains() {
+    let mut app = app();
+    let (_, person) = welder(&mut app);
+
+    step(&mut app, 1.0);
+    let first = level(&app, person, "welding");
+    assert_abs_diff_eq!(first, 1.0 - (-0.5f32).exp(), epsilon = 1e-5);
+    step(&mut app, 1.0);
+    let second = level(&app, person, "welding");
+    assert!(second > first && second < 1.0);
+    assert_abs_diff_eq!(
+        app.world().get::<Experience>(person).unwrap().years,
+        2.0,
+        epsilon = 1e-5
+    );
+
+    app.world_mut().entity_mut(person).insert(Absent);
+    step(&mut app, 1.0);
+    assert_eq!(level(&app, person, "welding"), second);
+    assert_abs_diff_eq!(
+        app.world().get::<Experience>(person).unwrap().years,
+        3.0,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn unused_skills_fade_but_persist() {
+    let mut app = app();
+    let (job, person) = welder(&mut app);
+    step(&mut app, 2.0);
+    let cooking = level(&app, person, "cooking");
+    assert_abs_diff_eq!(cooking, 0.8 * (-0.2f32).exp(), epsilon = 1e-5);
+
+    app.world_mut().despawn(job);
+    app.world_mut().entity_mut(person).remove::<Employment>();
+    let welding = level(&app, person, "welding");
+    step(&mut app, 1.0);
+    assert_abs_diff_eq!(
+        level(&app, person, "welding"),
+        welding * (-0.1f32).exp(),
+        epsilon = 1e-5
+    );
+    let years = app.world().get::<Experience>(person).unwrap().years;
+    step(&mut app, 1.0);
+    assert_eq!(app.world().get::<Experience>(person).unwrap().years, years);
+}
+
+#[test]
+fn hiring_prefers_skilled_applicants() {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(8));
+    let world = app.world_mut();
+    let job = world
+        .spawn(Job::builder().add_role(1, 1).trains("welding").build())
+        .id();
+    let novice = world.spawn((Age { years: 30 }, Unemployed)).id();
+    let skilled = world
+        .spawn((
+            Age { years: 30 },
+            Unemployed,
+            Skills(HashMap::from([("welding".to_string(), 0.7)])),
+        ))
+        .id();
+    app.update();
+
+    assert_eq!(
+        app.world().get::<Job>(job).unwrap().roles[0].1,
+        vec![skilled]
+    );
+    assert!(app.world().get::<Unemployed>(novice).is_some());
+}
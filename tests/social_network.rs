@@ -0,0 +1,205 @@
+// Social network generation (what the tests mean)
+//
+//   (1) Each tie model produces the structure it promises
+//       (lattice degree, BA edge count, ER mean degree, homophily by age)
+//   (2) A newborn is tied to both parents and to full siblings, as kin
+//   (3) Friendships fade without reinforcement and are dropped; kin ties stay
+//   (4) Seeding at startup links the existing population symmetrically
+//   (5) Maintenance leaves graphs with nothing to update unmarked
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Duration;
+
+use simrs::baby_spawner::BabyBorn;
+use simrs::baby_spawner::system::GameRNG;
+use simrs::graph::Graph;
+use simrs::person::{AgingConfig, Parents, Person};
+use simrs::social::{SocialConfig, SocialNetworkPlugin, SocialNode, Tie, TieModel, generate_ties};
+
+fn nodes(world: &mut World, ages: impl IntoIterator<Item = f32>) -> Vec<SocialNode> {
+    ages.into_iter()
+        .map(|age| SocialNode {
+            entity: world.spawn_empty().id(),
+            age,
+            traits: None,
+        })
+        .collect()
+}
+
+fn degrees(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let mut d = vec![0; n];
+    for &(i, j) in edges {
+        d[i] += 1;
+        d[j] += 1;
+    }
+    d
+}
+
+#[test]
+fn models_have_expected_structure() {
+    let mut world = World::new();
+    let ns = nodes(&mut world, (0..200).map(|i| 20.0 + (i % 50) as f32));
+    let mut rng = StdRng::seed_from_u64(2);
+
+    let lattice = generate_ties(&TieModel::SmallWorld { k: 4, rewire: 0.0 }, &ns, &mut rng);
+    assert!(degrees(200, &lattice).iter().all(|&d| d == 4));
+
+    let m = 3;
+    let ba = generate_ties(&TieModel::PreferentialAttachment { m }, &ns, &mut rng);
+    assert_eq!(ba.len(), m * (m + 1) / 2 + (200 - m - 1) * m);
+
+    let er = generate_ties(&TieModel::Random { mean_degree: 8.0 }, &ns, &mut rng);
+    let mean = 2.0 * er.len() as f64 / 200.0;
+    assert!((mean - 8.0).abs() < 1.0, "mean degree {mean}");
+
+    let model = TieModel::Homophily {
+        mean_degree: 8.0,
+        age_scale: 2.0,
+        personality_scale: 1.0,
+    };
+    let ties = generate_ties(&model, &ns, &mut rng);
+    let close = ties
+        .iter()
+        .filter(|&&(i, j)| (ns[i].age - ns[j].age).abs() <= 2.0)
+        .count();
+    assert!(
+        close * 2 > ties.len(),
+        "{close} of {} ties are between near-age peers",
+        ties.len()
+    );
+}
+
+fn app_with_social(cfg: SocialConfig) -> App {
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(1)));
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(cfg);
+    app.add_plugins(SocialNetworkPlugin);
+    app
+}
+
+fn step(app: &mut App, years: f64) {
+    app.world_mut()
+        .resource_mut::<Time<Real>>()
+        .update_with_duration(Duration::from_secs_f64(years));
+    app.update();
+}
+
+fn tie(app: &App, a: Entity, b: Entity) -> Option<Tie> {
+    app.world().get::<Graph<Tie>>(a)?.get(&b).copied()
+}
+
+fn quiet() -> SocialConfig {
+    SocialConfig {
+        seed_on_startup: false,
+        formation_per_year: 0.0,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn newborn_is_tied_to_parents_and_siblings() {
+    let mut app = app_with_social(quiet());
+    let world = app.world_mut();
+    let a = world.spawn(Person { age: 30.0 }).id();
+    let b = world.spawn(Person { age: 31.0 }).id();
+    let sibling = world.spawn((Person { age: 3.0 }, Parents(b, a))).id();
+    app.update();
+
+    let baby = app.world_mut().spawn((Person::new(), Parents(a, b))).id();
+    app.world_mut().send_event(BabyBorn {
+        entity: baby,
+        parents: Some((a, b)),
+    });
+    app.update();
+
+    for k in [a, b, sibling] {
+        assert!(tie(&app, baby, k).is_some_and(|t| t.kin));
+        assert!(tie(&app, k, baby).is_some_and(|t| t.kin));
+    }
+}
+
+#[test]
+fn friendships_fade_but_kin_stays() {
+    let mut app = app_with_social(SocialConfig {
+        reinforce_per_year: 0.0,
+        ..quiet()
+    });
+    let world = app.world_mut();
+    let a = world.spawn(Person { age: 30.0 }).id();
+    let friend = world.spawn(Person { age: 30.0 }).id();
+    let brother = world.spawn(Person { age: 30.0 }).id();
+    app.update();
+    {
+        let mut g = app.world_mut().get_mut::<Graph<Tie>>(a).unwrap();
+        g.set(friend, Tie::friend(0.5));
+        g.set(brother, Tie::kin(1.0));
+    }
+    step(&mut app, 0.0);
+    assert!(tie(&app, friend, a).is_some()); // mirrored
+
+    for _ in 0..10 {
+        step(&mut app, 1.0);
+    }
+    assert!(tie(&app, a, friend).is_none());
+    assert!(tie(&app, friend, a).is_none());
+    assert_eq!(tie(&app, a, brother), Some(Tie::kin(1.0)));
+}
+
+#[test]
+fn startup_seeding_links_population() {
+    let mut app = app_with_social(SocialConfig {
+        model: TieModel::SmallWorld { k: 4, rewire: 0.1 },
+        formation_per_year: 0.0,
+        ..Default::default()
+    });
+    let people: Vec<Entity> = (0..30)
+        .map(|_| app.world_mut().spawn(Person { age: 25.0 }).id())
+        .collect();
+    app.update();
+    app.update();
+
+    let total: usize = people
+        .iter()
+        .map(|&p| app.world().get::<Graph<Tie>>(p).unwrap().degree())
+        .sum();
+    assert_eq!(total, 30 * 4);
+    for &p in &people {
+        for (q, _) in app.world().get::<Graph<Tie>>(p).unwrap().iter() {
+            assert!(tie(&app, q, p).is_some());
+        }
+    }
+}
+
+#[test]
+fn maintenance_only_touches_changed_graphs() {
+    let mut app = app_with_social(quiet());
+    let world = app.world_mut();
+    let a = world.spawn(Person { age: 30.0 }).id();
+    let b = world.spawn(Person { age: 30.0 }).id();
+    let c = world.spawn(Person { age: 30.0 }).id();
+    app.update();
+    {
+        let mut g = app.world_mut().get_mut::<Graph<Tie>>(a).unwrap();
+        g.set(b, Tie::kin(1.0));
+        g.set(c, Tie::friend(0.5));
+    }
+    step(&mut app, 0.0);
+
+    let last_changed = |app: &App, e: Entity| {
+        app.world()
+            .entity(e)
+            .get_ref::<Graph<Tie>>()
+            .unwrap()
+            .last_changed()
+    };
+    let (kin_before, friend_before) = (last_changed(&app, b), last_changed(&app, c));
+    step(&mut app, 0.1);
+    assert_eq!(last_changed(&app, b), kin_before);
+    assert_ne!(last_changed(&app, c), friend_before);
+}
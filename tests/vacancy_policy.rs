@@ -0,0 +1,153 @@
+// Vacancies between min and max (what the tests mean)
+//
+//   (1) vacancy() reports required seats below min and optional ones above
+//       it: none by default, up to a share of max for Target, and as many
+//       as the funds cover for Budget; never past max
+//   (2) Hiring advertises and fills optional seats, not only required ones
+//   (3) Budget roles stop hiring when the job's account runs short
+//   (4) Job files choose a policy per role, and out-of-range ones are rejected
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+
+use simrs::economy::Account;
+use simrs::hiring_manager::{Age, Unemployed};
+use simrs::jobs::{
+    CatalogError, Funds, Job, JobCatalog, RoleSpec, Vacancy, VacancyPolicy, vacancy,
+};
+use simrs::{GregslistPlugin, HiringManagerPlugin};
+
+fn role(min: u32, max: u32, filled: u32, policy: VacancyPolicy) -> (RoleSpec, Vec<Entity>) {
+    let mut job = Job::builder()
+        .add_role(min, max)
+        .wage(100.0)
+        .vacancy_policy(policy)
+        .build();
+    let (spec, _) = job.roles.remove(0);
+    (spec, (0..filled).map(Entity::from_raw).collect())
+}
+
+fn open(required: u32, optional: u32) -> Vacancy {
+    Vacancy { required, optional }
+}
+
+#[test]
+fn policies_open_seats_between_min_and_max() {
+    use VacancyPolicy::*;
+    assert_eq!(vacancy(&role(2, 10, 0, Minimum), None), open(2, 0));
+    assert_eq!(vacancy(&role(2, 10, 0, Target(0.5)), None), open(2, 3));
+    assert_eq!(vacancy(&role(2, 10, 4, Target(0.5)), None), open(0, 1));
+    assert_eq!(vacancy(&role(2, 10, 7, Target(0.5)), None), open(0, 0));
+    assert_eq!(vacancy(&role(2, 10, 0, Target(1.0)), None), open(2, 8));
+
+    // 1000 pays 2 years of the 2 required seats plus 3 more
+    let funds = Funds {
+        balance: 1000.0,
+        payroll: 0.0,
+    };
+    assert_eq!(
+        vacancy(&role(2, 10, 0, Budget(2.0)), Some(funds)),
+        open(2, 3)
+    );
+    assert_eq!(
+        vacancy(&role(2, 4, 0, Budget(2.0)), Some(funds)),
+        open(2, 2)
+    );
+    let committed = Funds {
+        payroll: 400.0,
+        ..funds
+    };
+    assert_eq!(
+        vacancy(&role(2, 10, 4, Budget(2.0)), Some(committed)),
+        open(0, 1)
+    );
+    assert_eq!(vacancy(&role(2, 10, 0, Budget(2.0)), None), open(2, 0));
+
+    // min above max only asks for max
+    assert_eq!(vacancy(&role(5, 3, 1, Target(1.0)), None), open(2, 0));
+}
+
+fn hiring_app(policy: VacancyPolicy, balance: Option<f64>) -> (App, Entity) {
+    let mut app = App::new();
+    app.insert_resource(Time::<Real>::default());
+    app.add_plugins(GregslistPlugin::new(60.0))
+        .add_plugins(HiringManagerPlugin::new(3));
+    let world = app.world_mut();
+    let job = Job::builder()
+        .add_role(1, 6)
+        .age_gte(18)
+        .wage(100.0)
+        .vacancy_policy(policy)
+        .build();
+    let mut job = world.spawn(job);
+    if let Some(b) = balance {
+        job.insert(Account::new(b));
+    }
+    let job = job.id();
+    for _ in 0..10 {
+        world.spawn((Age { years: 30 }, Unemployed));
+    }
+    (app, job)
+}
+
+fn staff(app: &App, job: Entity) -> usize {
+    app.world().get::<Job>(job).unwrap().roles[0].1.len()
+}
+
+#[test]
+fn optional_seats_are_advertised_and_filled() {
+    let (mut app, minimum) = hiring_app(VacancyPolicy::Minimum, None);
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(staff(&app, minimum), 1);
+
+    let (mut app, full) = hiring_app(VacancyPolicy::Target(1.0), None);
+    app.update();
+    assert_eq!(staff(&app, full), 3, "batch size still applies");
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(staff(&app, full), 6);
+    let unemployed = app
+        .world_mut()
+        .query_filtered::<(), With<Unemployed>>()
+        .iter(app.world())
+        .count();
+    assert_eq!(unemployed, 4);
+}
+
+#[test]
+fn budget_limits_hiring() {
+    // 1 year of wages for 4 people
+    let (mut app, job) = hiring_app(VacancyPolicy::Budget(1.0), Some(400.0));
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(staff(&app, job), 4);
+}
+
+#[test]
+fn job_files_set_the_policy() {
+    let mut catalog = JobCatalog::default();
+    catalog
+        .load_str(
+            "a.ron",
+            r#"[(name: "clinic", roles: [(min: 1, max: 8, vacancy_policy: Target(0.5))])]"#,
+        )
+        .unwrap();
+    let clinic = catalog.instantiate("clinic").unwrap();
+    assert_eq!(clinic.roles[0].0.vacancy_policy, VacancyPolicy::Target(0.5));
+
+    let err = catalog
+        .load_str(
+            "b.ron",
+            r#"[(name: "shop", roles: [(min: 1, max: 2, vacancy_policy: Target(1.5))])]"#,
+        )
+        .unwrap_err();
+    let CatalogError::Invalid { problems, .. } = err else {
+        panic!("expected invalid, got {err}");
+    };
+    assert_eq!(problems, vec!["shop/role 0: Target(1.5) is outside 0..=1"]);
+}
@@ -0,0 +1,152 @@
+// Workplace ties from shared Job membership (what the tests mean)
+//
+//   (1) Members of the same job become acquainted, flagged as colleagues
+//   (2) Colleague ties strengthen the longer people work together,
+//       faster within a role than across roles
+//   (3) Leaving the job clears the flag and costs `workplace_leave_drop`
+//   (4) Members without a social graph (e.g. despawned) are ignored
+//   (5) Saturated ties under an unchanged roster are not rewritten
+
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Duration;
+
+use simrs::baby_spawner::system::GameRNG;
+use simrs::graph::Graph;
+use simrs::jobs::Job;
+use simrs::person::{AgingConfig, Person};
+use simrs::social::{SocialConfig, SocialNetworkPlugin, Tie};
+
+fn app() -> App {
+    let mut app = App::new();
+    app.insert_resource(GameRNG(StdRng::seed_from_u64(3)));
+    app.insert_resource(Time::<Real>::default());
+    app.insert_resource(AgingConfig { years_per_sec: 1.0 });
+    app.insert_resource(SocialConfig {
+        seed_on_startup: false,
+        formation_per_year: 0.0,
+        decay_per_year: 0.0,
+        reinforce_per_year: 0.0,
+        workplace_formation_per_year: 1_000.0,
+        ..Default::default()
+    });
+    app.add_plugins(SocialNetworkPlugin);
+    app
+}
+
+fn step(app: &mut App, years: f64) {
+    app.world_mut()
+        .resource_mut::<Time<Real>>()
+        .update_with_duration(Duration::from_secs_f64(years));
+    app.update();
+}
+
+fn tie(app: &App, a: Entity, b: Entity) -> Option<Tie> {
+    app.world().get::<Graph<Tie>>(a)?.get(&b).copied()
+}
+
+struct Fixture {
+    app: App,
+    job: Entity,
+    a: Entity,
+    b: Entity,
+    boss: Entity,
+}
+
+fn fixture() -> Fixture {
+    let mut app = app();
+    let world = app.world_mut();
+    let [a, b, boss] = std::array::from_fn(|_| world.spawn(Person { age: 30.0 }).id());
+    let mut job = Job::builder().add_role(0, 10).add_role(0, 1).build();
+    job.roles[0].1 = vec![a, b];
+    job.roles[1].1 = vec![boss];
+    let job = world.spawn(job).id();
+    step(&mut app, 0.0); // attach graphs; the first Real tick has no delta
+    step(&mut app, 0.01);
+    Fixture {
+        app,
+        job,
+        a,
+        b,
+        boss,
+    }
+}
+
+#[test]
+fn co_members_become_colleagues_and_grow_closer() {
+    let Fixture {
+        mut app,
+        a,
+        b,
+        boss,
+        ..
+    } = fixture();
+    let first = tie(&app, a, b).expect("same-role tie");
+    assert!(first.colleague && !first.kin);
+    assert_eq!(tie(&app, b, a), Some(first));
+    let cross = tie(&app, a, boss).expect("cross-role tie");
+
+    step(&mut app, 1.0);
+    let peer = tie(&app, a, b).unwrap();
+    let up = tie(&app, a, boss).unwrap();
+    assert!(peer.strength > first.strength);
+    assert!(peer.strength - first.strength > up.strength - cross.strength);
+}
+
+#[test]
+fn leaving_clears_flag_and_weakens() {
+    let Fixture {
+        mut app,
+        job,
+        a,
+        b,
+        boss,
+    } = fixture();
+    let before = tie(&app, a, b).unwrap();
+
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[0].1 = vec![a];
+    step(&mut app, 0.01);
+
+    let after = tie(&app, a, b).unwrap();
+    assert!(!after.colleague);
+    assert_eq!(tie(&app, b, a), Some(after));
+    let drop = app.world().resource::<SocialConfig>().workplace_leave_drop;
+    assert!((before.strength - drop - after.strength).abs() < 1e-3);
+    assert!(tie(&app, a, boss).unwrap().colleague);
+}
+
+#[test]
+fn departed_members_are_ignored() {
+    let Fixture {
+        mut app, job, a, ..
+    } = fixture();
+    let ghost = app.world_mut().spawn_empty().id();
+    app.world_mut().get_mut::<Job>(job).unwrap().roles[0]
+        .1
+        .push(ghost);
+    app.world_mut().despawn(ghost);
+    step(&mut app, 0.5);
+    assert!(tie(&app, a, ghost).is_none());
+}
+
+
+#[test]
+fn saturated_ties_are_left_alone() {
+    let Fixture { mut app, a, b, .. } = fixture();
+    step(&mut app, 1_000.0);
+    assert_eq!(tie(&app, a, b).unwrap().strength, 1.0);
+
+    let last_changed = |app: &App| {
+        app.world()
+            .entity(a)
+            .get_ref::<Graph<Tie>>()
+            .unwrap()
+            .last_changed()
+    };
+    let before = last_changed(&app);
+    step(&mut app, 0.5);
+    assert_eq!(last_changed(&app), before);
+}